@@ -2,7 +2,8 @@ use std::num::NonZeroUsize;
 
 use bdat::{label_hash, Label, ModernTable, TableAccessor};
 use game_data::character::{
-    Art, Attachment, Character, CharacterData, CharacterLang, Class, Costume, Skill, SoulHack,
+    Art, Attachment, Character, CharacterData, CharacterLang, Class, ClassGrowth, ClassLearnable,
+    Costume, GrowthTables, Guest, Skill, SoulHack,
 };
 
 use crate::lang::filter_table_from_bdat;
@@ -17,6 +18,8 @@ const UI_NAME_HASHES: [Label; 6] = [
     label_hash!("UIName6"),
 ];
 
+const CLASS_RANK_MAX: usize = 10;
+
 pub fn read_data(bdat: &BdatRegistry) -> CharacterData {
     let characters = bdat.table(label_hash!("CHR_PC"));
     let arts = bdat.table(label_hash!("BTL_Arts_PC"));
@@ -24,6 +27,8 @@ pub fn read_data(bdat: &BdatRegistry) -> CharacterData {
     let classes = bdat.table(label_hash!("BTL_Talent"));
     let attachments = bdat.table(label_hash!("MNU_Attachment"));
     let costumes_table = bdat.table(label_hash!("RSC_PcCostumeOpen"));
+    let growth = bdat.table(label_hash!("BTL_Grow"));
+    let npcs = bdat.table(label_hash!("FLD_NpcList"));
 
     let characters = read_id_name_pairs(characters).map(|(id, name)| {
         let pow_augment = characters.row(id).get(label_hash!("PowAugment")).as_str();
@@ -34,6 +39,10 @@ pub fn read_data(bdat: &BdatRegistry) -> CharacterData {
         Character {
             id,
             name_id: name,
+            default_class: characters
+                .row(id)
+                .get(label_hash!("DefTalent"))
+                .to_integer() as usize,
             pow_augment,
         }
     });
@@ -55,6 +64,7 @@ pub fn read_data(bdat: &BdatRegistry) -> CharacterData {
             label_hash!("EnSkillAchieve"),
         ),
     });
+    let class_growth: Vec<_> = classes.rows().map(|row| read_class_growth(&row)).collect();
     let classes = read_id_name_pairs(classes).map(|(id, name)| Class { id, name_id: name });
     let attachments =
         read_id_name_pairs(attachments).map(|(id, name)| Attachment { id, name_id: name });
@@ -65,7 +75,19 @@ pub fn read_data(bdat: &BdatRegistry) -> CharacterData {
         read_costume(&costumes_table, i, &mut costumes[i]);
     }
 
-    CharacterData::new(characters, arts, skills, classes, attachments, costumes)
+    CharacterData::new(
+        characters,
+        arts,
+        skills,
+        classes,
+        attachments,
+        costumes,
+        GrowthTables {
+            level_exp: read_level_exp(growth),
+            class_growth,
+        },
+        npcs.rows().filter_map(|row| read_guest(&row)),
+    )
 }
 
 pub fn read_lang(bdat: &LangBdatRegistry) -> CharacterLang {
@@ -74,6 +96,7 @@ pub fn read_lang(bdat: &LangBdatRegistry) -> CharacterLang {
     let skills = bdat.table(label_hash!("msg_btl_skill_name"));
     let classes = bdat.table(label_hash!("msg_btl_talent_name"));
     let misc = bdat.table(label_hash!("msg_mnu_char_ms"));
+    let npcs = bdat.table(label_hash!("msg_npc_name"));
 
     CharacterLang {
         characters: filter_table_from_bdat(characters),
@@ -81,6 +104,7 @@ pub fn read_lang(bdat: &LangBdatRegistry) -> CharacterLang {
         skills: filter_table_from_bdat(skills),
         classes: filter_table_from_bdat(classes),
         misc: filter_table_from_bdat(misc),
+        npcs: filter_table_from_bdat(npcs),
     }
 }
 
@@ -91,6 +115,69 @@ fn read_id_name_pairs<'a>(table: &'a ModernTable) -> impl Iterator<Item = (usize
     })
 }
 
+/// Reads the EXP curve. Each row holds the EXP needed to go from
+/// that level to the next, the save stores total EXP instead.
+fn read_level_exp(table: &ModernTable) -> Vec<u32> {
+    let mut total = 0;
+    table
+        .rows()
+        .map(|row| {
+            let level_total = total;
+            total += row.get(label_hash!("LevelExp")).to_integer();
+            level_total
+        })
+        .collect()
+}
+
+/// Reads rank progression for a class. Like the EXP curve, each `RankExp`
+/// column holds the CP needed to go from the previous rank to that rank.
+/// Ranks past the class' maximum have no CP requirement.
+fn read_class_growth(row: &ModernRow) -> ClassGrowth {
+    let mut rank_cp = Vec::with_capacity(CLASS_RANK_MAX);
+    let mut arts = Vec::new();
+    let mut skills = Vec::new();
+    let mut total = 0;
+    for rank in 1..=CLASS_RANK_MAX {
+        let cp = row.get(label_hash!(format!("RankExp{rank}"))).to_integer();
+        if rank > 1 && cp == 0 {
+            break;
+        }
+        total += cp;
+        rank_cp.push(total);
+
+        let rank = rank as u8;
+        let art = row.get(label_hash!(format!("RankArts{rank}"))).to_integer() as usize;
+        if art != 0 {
+            arts.push(ClassLearnable { id: art, rank });
+        }
+        let skill = row
+            .get(label_hash!(format!("RankSkill{rank}")))
+            .to_integer() as usize;
+        if skill != 0 {
+            skills.push(ClassLearnable { id: skill, rank });
+        }
+    }
+    ClassGrowth {
+        class_id: row.id(),
+        rank_cp: rank_cp.into(),
+        unlock_points: row.get(label_hash!("UnlockPoint")).to_integer() as u16,
+        arts: arts.into(),
+        skills: skills.into(),
+    }
+}
+
+/// Reads an `FLD_NpcList` row as a guest, skipping unnamed NPCs and NPCs that aren't
+/// tied to a quest (those never follow the party).
+fn read_guest(row: &ModernRow) -> Option<Guest> {
+    let name_id = row.get(label_hash!("Name")).to_integer() as usize;
+    let quest_id = row.get(label_hash!("QuestID")).to_integer() as usize;
+    (name_id != 0 && quest_id != 0).then_some(Guest {
+        id: row.id(),
+        name_id,
+        quest_id,
+    })
+}
+
 fn read_costume(table: &ModernTable, char_id: usize, out: &mut Vec<Costume>) {
     for row in table.rows() {
         let id = row.id();
@@ -0,0 +1,50 @@
+use bdat::{label_hash, TableAccessor};
+use game_data::collepedia::{CollepediaEntry, CollepediaRegistry, CollepediaReward};
+
+use crate::{BdatRegistry, ModernRow};
+
+/// Reads Collectopedia entries and rewards. See the [`CollepediaEntry`] and
+/// [`CollepediaReward`] fields for the columns they are read from.
+pub fn read_collepedia(bdat: &BdatRegistry) -> CollepediaRegistry {
+    let entries = bdat.table(label_hash!("ITM_Collepedia"));
+    let rewards = bdat.table(label_hash!("FLD_CollepediaReward"));
+
+    let entries = entries.rows().filter_map(read_entry);
+    let rewards = rewards.rows().filter_map(read_reward);
+
+    CollepediaRegistry::new(entries, rewards)
+}
+
+fn read_entry(row: ModernRow) -> Option<CollepediaEntry> {
+    let region = row.get(label_hash!("Area")).to_integer() as usize;
+    let flag = row.get(label_hash!("Flag")).to_integer() as usize;
+    // Unused entries have no region or flag
+    if region == 0 || flag == 0 {
+        return None;
+    }
+
+    Some(CollepediaEntry {
+        id: row.id().try_into().unwrap(),
+        region,
+        item_id: row.get(label_hash!("ItemID")).to_integer(),
+        amount: row.get(label_hash!("ItemNum")).to_integer(),
+        flag,
+    })
+}
+
+fn read_reward(row: ModernRow) -> Option<CollepediaReward> {
+    let region = row.get(label_hash!("Area")).to_integer() as usize;
+    let flag = row.get(label_hash!("Flag")).to_integer() as usize;
+    if region == 0 || flag == 0 {
+        return None;
+    }
+
+    Some(CollepediaReward {
+        id: row.id().try_into().unwrap(),
+        region,
+        required: row.get(label_hash!("Count")).to_integer() as usize,
+        item_id: row.get(label_hash!("Reward")).to_integer(),
+        amount: row.get(label_hash!("RewardNum")).to_integer(),
+        flag,
+    })
+}
@@ -4,12 +4,14 @@ use crate::{BdatRegistry, LangBdatRegistry};
 
 mod challenge;
 mod masha;
+mod pair;
 pub mod pow_augment;
 
 pub fn read_dlc_game(bdat: &BdatRegistry) -> DlcData {
     DlcData {
         masha: masha::read_game(bdat),
         challenge: challenge::read_game(bdat),
+        pairs: pair::read_game(bdat),
     }
 }
 
@@ -0,0 +1,21 @@
+use bdat::label_hash;
+use game_data::dlc::pair::{PairRegistry, UnityPair};
+
+use crate::BdatRegistry;
+
+pub fn read_game(bdat: &BdatRegistry) -> PairRegistry {
+    // Table is absent from no-DLC dumps
+    let Some(pairs) = bdat.get_table(label_hash!("BTL_Pair")) else {
+        return PairRegistry::default();
+    };
+    // Pair IDs are stored as bytes, other rows can't be selected in-game
+    PairRegistry::new(pairs.rows().filter_map(|row| {
+        Some(UnityPair {
+            id: row.id().try_into().ok()?,
+            characters: [
+                row.get(label_hash!("PC1")).to_integer() as usize,
+                row.get(label_hash!("PC2")).to_integer() as usize,
+            ],
+        })
+    }))
+}
@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::{collections::BTreeMap, num::NonZeroUsize};
 
 use bdat::{label_hash, Label};
 use enum_map::enum_map;
@@ -32,21 +32,33 @@ pub fn load_items(bdat: &BdatRegistry) -> ItemRegistry {
             .filter_map(|row| read_item(item_type, row))
             .for_each(|item| registry.register_item(item));
     }
+    // Future Redeemed battle manuals are key items
+    registry.register_battle_manual_type(ItemType::Precious);
 
     let gems = bdat.table(label_hash!("ITM_Gem"));
-    let mut registered = 0u64;
+    let mut categories: BTreeMap<u32, (usize, Vec<(u32, u32)>)> = BTreeMap::new();
     for row in gems.rows() {
         let category = row.get(label_hash!("Category")).to_integer();
-        if registered & (1 << category) == 0 {
-            registered |= 1 << category;
-
+        let (_, gems) = categories.entry(category).or_insert_with(|| {
             let name_id = row.get(label_hash!("Name")).to_integer() as usize;
-
-            registry.register_gem_category(GemCategory {
-                id: category,
-                name_id,
-            });
-        }
+            (name_id, Vec::new())
+        });
+        let level = row.get(label_hash!("Level")).to_integer();
+        gems.push((level, row.id().try_into().unwrap()));
+    }
+    for (id, (name_id, mut gems)) in categories {
+        gems.sort_unstable_by_key(|&(level, _)| level);
+        assert!(
+            gems.iter()
+                .map(|&(level, _)| level)
+                .eq(1..=gems.len() as u32),
+            "gem levels for category {id} are not contiguous"
+        );
+        registry.register_gem_category(GemCategory {
+            id,
+            name_id,
+            gem_ids: gems.into_iter().map(|(_, id)| id).collect(),
+        });
     }
 
     registry
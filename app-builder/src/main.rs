@@ -3,6 +3,7 @@ use game_data::{GameData, LanguageData};
 use std::{borrow::Borrow, collections::HashMap, fs::File, io::BufReader, path::Path};
 
 mod character;
+mod collepedia;
 mod dlc;
 mod enemy;
 mod enhance;
@@ -14,6 +15,7 @@ mod manual;
 mod ouroboros;
 mod quest;
 mod scenario;
+mod system;
 
 pub type ModernRow<'a, 'b> = RowRef<'a, 'b, ModernCell<'a, 'b>>;
 
@@ -64,9 +66,11 @@ fn read_game_data(bdat: &BdatRegistry) -> GameData {
         quests: quest::read_quests(bdat),
         characters: character::read_data(bdat),
         ouroboros: ouroboros::read_ouroboros(bdat),
+        collepedia: collepedia::read_collepedia(bdat),
         field: field::read_data(bdat),
         enemies: enemy::read_data(bdat),
         formation: formation::read_data(bdat),
+        system: system::read_data(bdat),
     }
 }
 
@@ -80,6 +84,7 @@ fn read_lang_data(bdat: &LangBdatRegistry) -> LanguageData {
         field: field::read_lang(bdat),
         enemies: enemy::read_lang(bdat),
         formation: formation::read_lang(bdat),
+        system: system::read_lang(bdat),
     }
 }
 
@@ -1,4 +1,5 @@
 use bdat::{label_hash, Label};
+use game_data::ouroboros::OuroNodeKind;
 use game_data::ouroboros::OuroTreeNode;
 use game_data::ouroboros::Ouroboros;
 use game_data::ouroboros::OuroborosRegistry;
@@ -32,21 +33,44 @@ pub fn read_ouroboros(bdat: &BdatRegistry) -> OuroborosRegistry {
 
 fn read_ouro_tree(bdat: &BdatRegistry, i: usize) -> Box<[OuroTreeNode]> {
     let table = bdat.table(&TABLES[i]);
-    let mut tree: Box<[OuroTreeNode]> = table
+    let mut nodes: Vec<(usize, OuroNodeKind, u32, Vec<usize>)> = table
         .rows()
         .map(|row| {
             let ty = row.get(label_hash!("Type")).to_integer();
             let param = row.get(label_hash!("Param")).to_integer() as usize;
+            let sp_cost = row.get(label_hash!("NeedSp")).to_integer();
+            let prerequisites = [label_hash!("Condition1"), label_hash!("Condition2")]
+                .into_iter()
+                .map(|label| row.get(label).to_integer() as usize)
+                .filter(|&id| id != 0)
+                .collect();
 
-            (match ty {
-                1 => OuroTreeNode::UnlockArt,
-                2 => OuroTreeNode::UnlockSkill,
-                3 => OuroTreeNode::UpgradeArt,
-                4 => OuroTreeNode::UpgradeSkill,
+            let kind = (match ty {
+                1 => OuroNodeKind::UnlockArt,
+                2 => OuroNodeKind::UnlockSkill,
+                3 => OuroNodeKind::UpgradeArt,
+                4 => OuroNodeKind::UpgradeSkill,
                 n => panic!("unknown node type {n}"),
-            })(param)
+            })(param);
+            (row.id(), kind, sp_cost, prerequisites)
         })
         .collect();
-    tree.sort_unstable();
-    tree
+    nodes.sort_unstable_by_key(|(_, kind, _, _)| *kind);
+
+    // Prerequisites refer to row IDs, convert them to node IDs (sorted index + 1)
+    let node_id = |row_id: usize| {
+        nodes
+            .iter()
+            .position(|(id, _, _, _)| *id == row_id)
+            .map(|i| i + 1)
+            .expect("unknown prerequisite node")
+    };
+    nodes
+        .iter()
+        .map(|(_, kind, sp_cost, prerequisites)| OuroTreeNode {
+            kind: *kind,
+            sp_cost: *sp_cost,
+            prerequisites: prerequisites.iter().map(|&id| node_id(id)).collect(),
+        })
+        .collect()
 }
@@ -12,7 +12,7 @@ pub fn read_scenario_events(bdat: &BdatRegistry) -> ScenarioRanges {
     let cond_list = bdat.table(label_hash!("FLD_ConditionList"));
     let cond_scenario = bdat.table(label_hash!("FLD_ConditionScenario"));
 
-    let (ranges_base, ranges_dlc4): (Vec<_>, Vec<_>) = menu_scenario
+    let (ranges_dlc4, ranges_base): (Vec<_>, Vec<_>) = menu_scenario
         .rows()
         .flat_map(|row| {
             let base_cond = row.get(label_hash!("ScenarioCond")).to_integer();
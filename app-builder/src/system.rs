@@ -0,0 +1,53 @@
+use bdat::{label_hash, TableAccessor};
+use game_data::system::{GameOption, SystemLang, SystemRegistry, TheaterEvent};
+
+use crate::{lang::text_table_from_bdat, BdatRegistry, LangBdatRegistry};
+
+/// Tables that define global settings. Not all of them are present in
+/// every game version.
+const OPTION_TABLES: &[&str] = &[
+    "MNU_option_camera",
+    "MNU_option_control",
+    "MNU_option_display",
+    "MNU_option_game",
+    "MNU_option_notice",
+    "MNU_option_sound",
+];
+
+pub fn read_data(bdat: &BdatRegistry) -> SystemRegistry {
+    let options = OPTION_TABLES
+        .iter()
+        .filter_map(|&name| bdat.get_table(label_hash!(name)).map(|t| (name, t)))
+        .flat_map(|(name, table)| {
+            table.rows().map(move |row| {
+                let name_id = row.get(label_hash!("Name")).to_integer() as usize;
+                GameOption {
+                    option_id: row.get(label_hash!("option_id")).to_integer() as usize,
+                    table: name.into(),
+                    row_id: row.id(),
+                    name_id: (name_id != 0).then_some(name_id),
+                }
+            })
+        });
+
+    let events = bdat
+        .table(label_hash!("MNU_EventTheater"))
+        .rows()
+        .map(|row| {
+            let name_id = row.get(label_hash!("Name")).to_integer() as usize;
+            TheaterEvent {
+                id: row.id(),
+                chapter: row.get(label_hash!("Chapter")).to_integer(),
+                name_id: (name_id != 0).then_some(name_id),
+            }
+        });
+
+    SystemRegistry::new(options, events)
+}
+
+pub fn read_lang(bdat: &LangBdatRegistry) -> SystemLang {
+    SystemLang {
+        options: text_table_from_bdat(bdat.table(label_hash!("msg_mnu_option"))),
+        events: text_table_from_bdat(bdat.table(label_hash!("msg_mnu_event_theater"))),
+    }
+}
@@ -0,0 +1,224 @@
+//! Portable class builds, that can be shared between save files.
+
+use recordkeeper::{
+    character::{
+        class::CharacterClass,
+        formation::CharacterFormation,
+        slot::{EmptySlot, EmptySlotMut, Slot},
+    },
+    item::{Inventory, ItemType},
+    SaveData,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{character::CharacterData, item::ItemRegistry, lang::Nameable, LanguageData};
+
+/// Arts, skills, gems and accessories equipped on a class.
+///
+/// Names are only included for readability, and are ignored when importing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClassBuild {
+    pub class: BuildEntry,
+    /// Art IDs, in slot order
+    pub arts: Vec<Option<BuildEntry>>,
+    /// Skill IDs, in slot order
+    pub skills: Vec<Option<BuildEntry>>,
+    /// Gem category IDs (`ITM_Gem.Category`), in slot order
+    pub gems: Vec<Option<BuildEntry>>,
+    /// Accessory item IDs, in slot order
+    pub accessories: Vec<Option<AccessoryEntry>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BuildEntry {
+    pub id: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AccessoryEntry {
+    #[serde(flatten)]
+    pub item: BuildEntry,
+    /// Present for accessories equipped by heroes, which don't refer to
+    /// the inventory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hero: Option<HeroAccessory>,
+}
+
+/// Raw reference of an accessory equipped by a hero, imported as-is.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct HeroAccessory {
+    pub slot_index: u16,
+    pub item_type: u16,
+}
+
+/// Parts of a build that could not be imported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuildIssue {
+    /// There is no such accessory in the inventory, so the slot was emptied.
+    MissingAccessory { slot: usize, item_id: usize },
+}
+
+impl ClassBuild {
+    /// Exports the build of a class from the save file format.
+    ///
+    /// If `lang` is present, names are resolved for all entries.
+    pub fn from_class(
+        class_id: usize,
+        class: &CharacterClass,
+        save: &SaveData,
+        characters: &CharacterData,
+        items: &ItemRegistry,
+        lang: Option<&LanguageData>,
+    ) -> Self {
+        let name = |entry: Option<&dyn Nameable>| {
+            lang.zip(entry)
+                .and_then(|(lang, entry)| entry.get_name_str(lang))
+                .map(ToString::to_string)
+        };
+        let entry = |id: usize, item: Option<&dyn Nameable>| BuildEntry {
+            id,
+            name: name(item),
+        };
+
+        let accessories = save.inventory.slots(ItemType::Accessory);
+        Self {
+            class: entry(class_id, dyn_name(characters.get_class(class_id))),
+            arts: slots(class.arts(), |id| {
+                let id = usize::from(id);
+                entry(id, dyn_name(characters.get_art(id)))
+            }),
+            skills: slots(class.skills(), |id| {
+                let id = usize::from(id);
+                entry(id, dyn_name(characters.get_skill(id)))
+            }),
+            gems: slots(class.gems(), |id| {
+                let id = u32::from(id) + 1;
+                entry(id as usize, dyn_name(items.gem_category_by_id(id)))
+            }),
+            accessories: class
+                .accessories()
+                .map(|slot| {
+                    // Accessory slots point to the inventory, resolve the item ID
+                    let slot = slot.get()?;
+                    let (item_id, hero) = if slot.is_from_inventory() {
+                        let item_id = accessories.get(usize::from(slot.slot_index()))?.item_id();
+                        (item_id, None)
+                    } else {
+                        let hero = HeroAccessory {
+                            slot_index: slot.slot_index(),
+                            item_type: slot.raw_item_type(),
+                        };
+                        (slot.bdat_id(), Some(hero))
+                    };
+                    let item = items.get_item(ItemType::Accessory, item_id.into());
+                    Some(AccessoryEntry {
+                        item: entry(usize::from(item_id), dyn_name(item)),
+                        hero,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Exports the build of a saved party formation's character.
+    ///
+    /// Returns `None` if the character has no selected class.
+    pub fn from_formation(
+        formation: &CharacterFormation,
+        save: &SaveData,
+        characters: &CharacterData,
+        items: &ItemRegistry,
+        lang: Option<&LanguageData>,
+    ) -> Option<Self> {
+        let class_id = usize::from(formation.current_class);
+        (class_id != 0)
+            .then(|| Self::from_class(class_id, &formation.class, save, characters, items, lang))
+    }
+
+    /// Imports the build into a character's class in the save file.
+    ///
+    /// The class is the one the build was exported from. See
+    /// [`ClassBuild::apply_to_class`] for details.
+    ///
+    /// ## Panics
+    /// Panics if the character or class IDs are out of bounds.
+    pub fn apply_to(&self, save: &mut SaveData, char_id: usize) -> Vec<BuildIssue> {
+        let index = char_id.checked_sub(1).expect("char ID must be >= 1");
+        let class = save.characters[index].class_data_mut(self.class.id);
+        self.apply_to_class(class, &save.inventory)
+    }
+
+    /// Imports the build into a class, e.g. one from a saved party formation.
+    ///
+    /// Accessories are re-linked to the first inventory slot holding the same
+    /// item. Accessories that are not in the inventory are left unequipped,
+    /// and reported in the returned list. Accessories equipped by heroes are
+    /// restored as they were exported.
+    ///
+    /// Class progress (CP, rank, unlock points) is not changed. Entries with
+    /// out-of-range IDs are imported as empty slots.
+    pub fn apply_to_class(
+        &self,
+        class: &mut CharacterClass,
+        inventory: &Inventory,
+    ) -> Vec<BuildIssue> {
+        let mut issues = Vec::new();
+
+        for (mut slot, entry) in class.arts_mut().zip(&self.arts) {
+            slot.set(entry.as_ref().and_then(|e| e.id.try_into().ok()));
+        }
+        for (mut slot, entry) in class.skills_mut().zip(&self.skills) {
+            slot.set(entry.as_ref().and_then(|e| e.id.try_into().ok()));
+        }
+        for (mut slot, entry) in class.gems_mut().zip(&self.gems) {
+            let category = entry.as_ref().and_then(|e| e.id.checked_sub(1));
+            slot.set(category.and_then(|c| c.try_into().ok()));
+        }
+
+        let accessories = inventory.slots(ItemType::Accessory);
+        for (i, (mut slot, entry)) in class.accessories_mut().zip(&self.accessories).enumerate() {
+            let Some(AccessoryEntry { item: entry, hero }) = entry else {
+                slot.set_empty();
+                continue;
+            };
+            if let Some(hero) = hero {
+                match entry.id.try_into() {
+                    Ok(id) => slot.set_raw(id, hero.slot_index, hero.item_type),
+                    Err(_) => slot.set_empty(),
+                }
+                continue;
+            }
+            match accessories
+                .iter()
+                .find(|s| s.is_valid() && usize::from(s.item_id()) == entry.id)
+            {
+                Some(inventory_slot) => slot.set_from_inventory(inventory_slot),
+                None => {
+                    slot.set_empty();
+                    issues.push(BuildIssue::MissingAccessory {
+                        slot: i,
+                        item_id: entry.id,
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+fn dyn_name<T: Nameable>(value: Option<&T>) -> Option<&dyn Nameable> {
+    value.map(|v| v as &dyn Nameable)
+}
+
+fn slots<N: Copy>(
+    slots: impl Iterator<Item = Slot<N>>,
+    map: impl Fn(N) -> BuildEntry,
+) -> Vec<Option<BuildEntry>>
+where
+    Slot<N>: EmptySlot,
+{
+    slots.map(|slot| slot.get().map(&map)).collect()
+}
@@ -6,6 +6,7 @@ use crate::{
     lang::{FilterEntry, FilterTable, Filterable, Id},
     LanguageData,
 };
+use recordkeeper::{SaveData, SystemData, COSTUME_MAX};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -16,6 +17,12 @@ pub struct CharacterData {
     classes: Box<[Class]>,
     attachments: Box<[Attachment]>,
     costumes: [Vec<Costume>; 6],
+    /// Total EXP required to reach each level, starting from level 1
+    level_exp: Box<[u32]>,
+    /// Rank progression for each class, indexed by class ID - 1
+    class_growth: Box<[ClassGrowth]>,
+    /// NPCs that can follow the party, sorted by ID
+    guests: Box<[Guest]>,
 
     pub pow_augment_characters: Box<[Character]>,
 }
@@ -27,12 +34,15 @@ pub struct CharacterLang {
     pub skills: FilterTable,
     pub classes: FilterTable,
     pub misc: FilterTable,
+    pub npcs: FilterTable,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub struct Character {
     pub id: usize,
     pub name_id: usize,
+    /// The class the character starts with (`DefTalent` in `CHR_PC`)
+    pub default_class: usize,
     pub pow_augment: Option<PowAugment>,
 }
 
@@ -62,6 +72,35 @@ pub struct Class {
     pub name_id: usize,
 }
 
+/// Rank progression data for a class.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct ClassGrowth {
+    pub class_id: usize,
+    /// Total CP required to reach each rank, starting from rank 1
+    pub rank_cp: Box<[u32]>,
+    /// Unlock points required for the class to become available
+    pub unlock_points: u16,
+    /// Arts that are learned (and can be inherited) when ranking up
+    pub arts: Box<[ClassLearnable]>,
+    /// Skills that are learned (and can be inherited) when ranking up
+    pub skills: Box<[ClassLearnable]>,
+}
+
+/// Character level and class rank progression tables, see [`CharacterData::new`].
+pub struct GrowthTables {
+    /// Total EXP required to reach each level, starting from level 1
+    pub level_exp: Vec<u32>,
+    /// Rank progression for each class, indexed by class ID - 1
+    pub class_growth: Vec<ClassGrowth>,
+}
+
+/// An art or skill that is learned when a class reaches a certain rank.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct ClassLearnable {
+    pub id: usize,
+    pub rank: u8,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
 pub struct Attachment {
     pub id: usize,
@@ -74,7 +113,31 @@ pub struct Costume {
     pub name_id: usize,
 }
 
+/// An NPC that can join the party as a guest, from `FLD_NpcList`.
+///
+/// Only named NPCs that are tied to a quest (`QuestID`) are included, as those are
+/// the ones the game lets follow the party.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct Guest {
+    pub id: usize,
+    pub name_id: usize,
+    /// The quest the NPC joins the party for
+    pub quest_id: usize,
+}
+
+/// Reasons why a character's equipped costume is not valid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CostumeError {
+    /// The costume ID is not a known costume for the character.
+    Unknown(usize),
+    /// The costume exists, but it is not unlocked in the system file.
+    Locked(usize),
+    /// The costume ID can't be stored in the system file.
+    OutOfBounds(usize),
+}
+
 impl CharacterData {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         characters: impl IntoIterator<Item = Character>,
         arts: impl IntoIterator<Item = Art>,
@@ -82,14 +145,21 @@ impl CharacterData {
         classes: impl IntoIterator<Item = Class>,
         attachments: impl IntoIterator<Item = Attachment>,
         costumes: [Vec<Costume>; 6],
+        growth: GrowthTables,
+        guests: impl IntoIterator<Item = Guest>,
     ) -> Self {
         let characters: Box<[Character]> = characters.into_iter().collect();
+        let mut guests: Box<[Guest]> = guests.into_iter().collect();
+        guests.sort_unstable_by_key(|g| g.id);
         Self {
             arts: arts.into_iter().collect(),
             skills: skills.into_iter().collect(),
             classes: classes.into_iter().collect(),
             attachments: attachments.into_iter().collect(),
             costumes,
+            level_exp: growth.level_exp.into(),
+            class_growth: growth.class_growth.into(),
+            guests,
             pow_augment_characters: characters
                 .clone()
                 .into_vec()
@@ -132,6 +202,18 @@ impl CharacterData {
         &self.skills
     }
 
+    /// Returns the guest NPC with the given `FLD_NpcList` ID.
+    pub fn get_guest(&self, id: usize) -> Option<&Guest> {
+        self.guests
+            .binary_search_by_key(&id, |g| g.id)
+            .ok()
+            .map(|i| &self.guests[i])
+    }
+
+    pub fn guests(&self) -> &[Guest] {
+        &self.guests
+    }
+
     pub fn attachments(&self) -> &[Attachment] {
         &self.attachments
     }
@@ -142,6 +224,225 @@ impl CharacterData {
             .and_then(|i| self.costumes.get(i))
             .unwrap_or_else(|| &self.costumes[0])
     }
+
+    /// Returns the total EXP required to reach each level, starting from
+    /// level 1 (index 0).
+    pub fn level_exp(&self) -> &[u32] {
+        &self.level_exp
+    }
+
+    pub fn max_level(&self) -> u32 {
+        self.level_exp.len() as u32
+    }
+
+    /// Returns the IDs of all characters whose level doesn't match their EXP.
+    ///
+    /// Characters that haven't been initialized (level 0) are ignored.
+    pub fn inconsistent_levels<'a>(
+        &'a self,
+        save: &'a SaveData,
+    ) -> impl Iterator<Item = usize> + 'a {
+        save.characters
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.level != 0 && !c.is_level_consistent(&self.level_exp))
+            .map(|(i, _)| i + 1)
+    }
+
+    /// Returns the costumes for a character, along with whether they
+    /// are unlocked in the system file.
+    ///
+    /// Characters that can't wear costumes have no entries.
+    pub fn costume_status<'a>(
+        &'a self,
+        char_id: usize,
+        system: &'a SystemData,
+    ) -> impl Iterator<Item = (&'a Costume, Result<bool, CostumeError>)> + 'a {
+        self.character_costumes(char_id)
+            .iter()
+            .map(move |costume| (costume, costume.is_unlocked(char_id, system)))
+    }
+
+    /// Unlocks all of a character's costumes in the system file.
+    ///
+    /// If any of the costumes can't be stored in the system file, nothing
+    /// is unlocked.
+    pub fn unlock_costumes(
+        &self,
+        char_id: usize,
+        system: &mut SystemData,
+    ) -> Result<(), CostumeError> {
+        let costumes = self.character_costumes(char_id);
+        if let Some(costume) = costumes.iter().find(|c| c.id >= COSTUME_MAX) {
+            return Err(CostumeError::OutOfBounds(costume.id));
+        }
+        for costume in costumes {
+            costume.set_unlocked(char_id, system, true)?;
+        }
+        Ok(())
+    }
+
+    /// Checks whether the costume currently equipped by a character is
+    /// available for them, and unlocked in the system file.
+    ///
+    /// ## Panics
+    /// Panics if the character ID is out of bounds for the save file.
+    pub fn validate_costume(
+        &self,
+        char_id: usize,
+        save: &SaveData,
+        system: &SystemData,
+    ) -> Result<(), CostumeError> {
+        let costume_id = save.characters[char_id.checked_sub(1).expect("invalid character ID")]
+            .costume_id as usize;
+        if costume_id == 0 {
+            // Default outfit
+            return Ok(());
+        }
+        let costume = self
+            .character_costumes(char_id)
+            .iter()
+            .find(|c| c.id == costume_id)
+            .ok_or(CostumeError::Unknown(costume_id))?;
+        if !costume.is_unlocked(char_id, system)? {
+            return Err(CostumeError::Locked(costume_id));
+        }
+        Ok(())
+    }
+
+    /// Returns whether the class is only available in Future Redeemed, i.e. if
+    /// it is the default class of a Future Redeemed character.
+    pub fn is_dlc4_class(&self, class_id: usize) -> bool {
+        self.characters
+            .iter()
+            .any(|c| c.is_dlc4() && c.default_class == class_id)
+    }
+
+    /// Returns rank progression data for the given class.
+    pub fn class_growth(&self, class_id: usize) -> Option<&ClassGrowth> {
+        class_id
+            .checked_sub(1)
+            .and_then(|id| self.class_growth.get(id))
+    }
+
+    /// Sets a character's class to the given rank, with the CP and unlock points
+    /// that rank requires.
+    ///
+    /// For main characters, the class is also registered as unlocked, and the
+    /// arts and skills learned up to that rank become available for inheritance.
+    /// Lowering the rank does not forget previously learned arts and skills.
+    ///
+    /// ## Panics
+    /// Panics if the character or class IDs are out of bounds, or if the rank
+    /// is higher than the class' maximum rank.
+    pub fn set_class_rank(&self, save: &mut SaveData, char_id: usize, class_id: usize, rank: u8) {
+        let growth = self.class_growth(class_id).expect("unknown class ID");
+        let main_index = main_character_index(save, char_id);
+
+        let class = save.characters[char_id.checked_sub(1).expect("invalid character ID")]
+            .class_data_mut(class_id);
+        class.set_rank(rank, &growth.rank_cp);
+        if rank == 0 {
+            return;
+        }
+        class.unlock_points = class.unlock_points.max(growth.unlock_points);
+
+        let Some(index) = main_index else { return };
+        let chrono = &mut save.chronological_data;
+        if !chrono.character_classes.contains(index, class_id - 1) {
+            chrono.character_classes.insert(index, class_id - 1);
+        }
+        for art in growth.arts.iter().filter(|a| a.rank <= rank) {
+            chrono.learn_character_art(index, art.id);
+        }
+        for skill in growth.skills.iter().filter(|s| s.rank <= rank) {
+            chrono.learn_character_skill(index, skill.id);
+        }
+    }
+
+    /// Sets a character's class to its maximum rank, learning all of its
+    /// inheritable arts and skills.
+    ///
+    /// ## Panics
+    /// Panics if the character or class IDs are out of bounds.
+    pub fn master_class(&self, save: &mut SaveData, char_id: usize, class_id: usize) {
+        let max_rank = self
+            .class_growth(class_id)
+            .expect("unknown class ID")
+            .max_rank();
+        self.set_class_rank(save, char_id, class_id, max_rank);
+    }
+
+    /// Masters every class for every character.
+    ///
+    /// Main characters get all classes, while other characters (i.e. heroes)
+    /// only get their already unlocked classes mastered. Only classes from the
+    /// save's game mode (base game or Future Redeemed) are considered.
+    pub fn master_all_classes(&self, save: &mut SaveData) {
+        let is_dlc4 = save.is_dlc4();
+        for char_id in 1..=save.characters.len() {
+            let is_main = main_character_index(save, char_id).is_some();
+            for growth in self
+                .class_growth
+                .iter()
+                .filter(|g| !g.rank_cp.is_empty() && self.is_dlc4_class(g.class_id) == is_dlc4)
+            {
+                let class = save.characters[char_id - 1].class_data(growth.class_id);
+                if is_main || class.rank() != 0 {
+                    self.master_class(save, char_id, growth.class_id);
+                }
+            }
+        }
+    }
+
+    fn character_costumes(&self, char_id: usize) -> &[Costume] {
+        char_id
+            .checked_sub(1)
+            .and_then(|i| self.costumes.get(i))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+impl ClassGrowth {
+    pub fn max_rank(&self) -> u8 {
+        self.rank_cp.len() as u8
+    }
+}
+
+impl Costume {
+    /// Returns whether the costume is unlocked for the given character
+    /// in the system file.
+    pub fn is_unlocked(&self, char_id: usize, system: &SystemData) -> Result<bool, CostumeError> {
+        self.check_bounds()?;
+        Ok(system.is_costume_unlocked(self.id, char_id))
+    }
+
+    /// Updates the unlock status of the costume for the given character
+    /// in the system file.
+    pub fn set_unlocked(
+        &self,
+        char_id: usize,
+        system: &mut SystemData,
+        unlocked: bool,
+    ) -> Result<(), CostumeError> {
+        self.check_bounds()?;
+        system.set_costume_unlocked(self.id, char_id, unlocked);
+        Ok(())
+    }
+
+    fn check_bounds(&self) -> Result<(), CostumeError> {
+        if self.id >= COSTUME_MAX {
+            return Err(CostumeError::OutOfBounds(self.id));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the index of a main character in tables that only track
+/// main characters.
+fn main_character_index(save: &SaveData, char_id: usize) -> Option<usize> {
+    save.main_character_ids().position(|id| id == char_id)
 }
 
 impl Character {
@@ -174,6 +475,12 @@ impl Filterable for Class {
     }
 }
 
+impl Filterable for Guest {
+    fn get_filter<'l>(&self, language: &'l LanguageData) -> Option<&'l FilterEntry> {
+        language.characters.npcs.get(self.name_id)
+    }
+}
+
 impl Filterable for Attachment {
     fn get_filter<'l>(&self, language: &'l LanguageData) -> Option<&'l FilterEntry> {
         language.characters.misc.get(self.name_id)
@@ -210,6 +517,12 @@ impl Id for Character {
     }
 }
 
+impl Id for Guest {
+    fn id(&self) -> usize {
+        self.id
+    }
+}
+
 impl Id for Attachment {
     fn id(&self) -> usize {
         self.id
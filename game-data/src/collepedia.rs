@@ -0,0 +1,210 @@
+//! Base game Collectopedia: per-region entries, turn-in state and rewards.
+//!
+//! Collectopedia progress is not stored in the inventory, it is tracked
+//! through 1-bit flags instead.
+
+use recordkeeper::{flags::FlagType, SaveData};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Default, PartialEq)]
+pub struct CollepediaRegistry {
+    entries: Box<[CollepediaEntry]>,
+    rewards: Box<[CollepediaReward]>,
+}
+
+/// A Collectopedia entry (`ITM_Collepedia`)
+///
+/// The entry's name is the name of the Collectopedia item with the same ID.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct CollepediaEntry {
+    pub id: u32,
+    /// Map ID of the region the entry belongs to (`Area`)
+    pub region: usize,
+    /// Collectible (`ITM_Collection`) that needs to be turned in (`ItemID`)
+    pub item_id: u32,
+    /// `ItemNum`
+    pub amount: u32,
+    /// Set when the entry has been turned in (`Flag`)
+    pub flag: usize,
+}
+
+/// A reward for turning in entries in a region (`FLD_CollepediaReward`)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct CollepediaReward {
+    pub id: u32,
+    /// Map ID of the region the reward belongs to (`Area`)
+    pub region: usize,
+    /// Number of entries that must be turned in within the region (`Count`)
+    pub required: usize,
+    /// Item ID (any type) of the reward (`Reward`)
+    pub item_id: u32,
+    /// `RewardNum`
+    pub amount: u32,
+    /// Set when the reward has been claimed (`Flag`)
+    pub flag: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionProgress {
+    pub turned_in: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollepediaError {
+    UnknownEntry(u32),
+    UnknownReward(u32),
+    /// Not enough entries in the region have been turned in to claim
+    /// the reward.
+    NotEnoughEntries {
+        required: usize,
+        turned_in: usize,
+    },
+}
+
+impl CollepediaRegistry {
+    pub fn new(
+        entries: impl IntoIterator<Item = CollepediaEntry>,
+        rewards: impl IntoIterator<Item = CollepediaReward>,
+    ) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+            rewards: rewards.into_iter().collect(),
+        }
+    }
+
+    pub fn entries(&self) -> &[CollepediaEntry] {
+        &self.entries
+    }
+
+    pub fn rewards(&self) -> &[CollepediaReward] {
+        &self.rewards
+    }
+
+    pub fn get_entry(&self, id: u32) -> Option<&CollepediaEntry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    pub fn get_reward(&self, id: u32) -> Option<&CollepediaReward> {
+        self.rewards.iter().find(|r| r.id == id)
+    }
+
+    /// Returns the map IDs of all regions with Collectopedia entries, in
+    /// ascending order.
+    pub fn regions(&self) -> Vec<usize> {
+        let mut regions = self.entries.iter().map(|e| e.region).collect::<Vec<_>>();
+        regions.sort_unstable();
+        regions.dedup();
+        regions
+    }
+
+    pub fn region_entries(&self, region: usize) -> impl Iterator<Item = &CollepediaEntry> {
+        self.entries.iter().filter(move |e| e.region == region)
+    }
+
+    /// Returns the rewards for a region, in the order they appear in the
+    /// game files.
+    pub fn region_rewards(&self, region: usize) -> impl Iterator<Item = &CollepediaReward> {
+        self.rewards.iter().filter(move |r| r.region == region)
+    }
+
+    pub fn region_progress(&self, save: &SaveData, region: usize) -> RegionProgress {
+        let mut progress = RegionProgress {
+            turned_in: 0,
+            total: 0,
+        };
+        for entry in self.region_entries(region) {
+            progress.total += 1;
+            if entry.is_turned_in(save) {
+                progress.turned_in += 1;
+            }
+        }
+        progress
+    }
+
+    /// Marks an entry as turned in, or resets it.
+    ///
+    /// Resetting an entry also un-claims rewards in the region that would
+    /// no longer be available. The required collectibles are not taken from
+    /// (or given back to) the inventory.
+    pub fn set_turned_in(
+        &self,
+        save: &mut SaveData,
+        entry_id: u32,
+        turned_in: bool,
+    ) -> Result<(), CollepediaError> {
+        let entry = self
+            .get_entry(entry_id)
+            .ok_or(CollepediaError::UnknownEntry(entry_id))?;
+        set_flag(save, entry.flag, turned_in);
+
+        if !turned_in {
+            let progress = self.region_progress(save, entry.region);
+            for reward in self.region_rewards(entry.region) {
+                if reward.required > progress.turned_in {
+                    set_flag(save, reward.flag, false);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks a reward as claimed, or resets it.
+    ///
+    /// The reward item is not added to the inventory.
+    pub fn set_claimed(
+        &self,
+        save: &mut SaveData,
+        reward_id: u32,
+        claimed: bool,
+    ) -> Result<(), CollepediaError> {
+        let reward = self
+            .get_reward(reward_id)
+            .ok_or(CollepediaError::UnknownReward(reward_id))?;
+
+        if claimed {
+            let progress = self.region_progress(save, reward.region);
+            if progress.turned_in < reward.required {
+                return Err(CollepediaError::NotEnoughEntries {
+                    required: reward.required,
+                    turned_in: progress.turned_in,
+                });
+            }
+        }
+        set_flag(save, reward.flag, claimed);
+        Ok(())
+    }
+
+    /// Turns in every entry in the region and claims all of its rewards.
+    pub fn complete_region(&self, save: &mut SaveData, region: usize) {
+        for entry in self.region_entries(region) {
+            set_flag(save, entry.flag, true);
+        }
+        for reward in self.region_rewards(region) {
+            set_flag(save, reward.flag, true);
+        }
+    }
+}
+
+impl CollepediaEntry {
+    pub fn is_turned_in(&self, save: &SaveData) -> bool {
+        get_flag(save, self.flag)
+    }
+}
+
+impl CollepediaReward {
+    pub fn is_claimed(&self, save: &SaveData) -> bool {
+        get_flag(save, self.flag)
+    }
+}
+
+fn get_flag(save: &SaveData, index: usize) -> bool {
+    save.flags
+        .get(FlagType::Bit, index)
+        .expect("flag out of bounds")
+        != 0
+}
+
+fn set_flag(save: &mut SaveData, index: usize, value: bool) {
+    save.flags.set(FlagType::Bit, index, value.into())
+}
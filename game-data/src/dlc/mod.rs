@@ -3,16 +3,19 @@ use serde::{Deserialize, Serialize};
 use self::{
     challenge::{ChallengeGame, ChallengeLang},
     masha::{GameCraftItems, LangCraftItems},
+    pair::PairRegistry,
 };
 
 pub mod challenge;
 pub mod masha;
+pub mod pair;
 pub mod pow_augment;
 
 #[derive(Serialize, Deserialize)]
 pub struct DlcData {
     pub masha: GameCraftItems,
     pub challenge: ChallengeGame,
+    pub pairs: PairRegistry,
 }
 
 #[derive(Serialize, Deserialize)]
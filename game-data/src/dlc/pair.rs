@@ -0,0 +1,81 @@
+//! Unity Combo pairs (Future Redeemed)
+
+use recordkeeper::{dlc::DLC4_PAIR_MAX, SaveData};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct PairRegistry {
+    pairs: Box<[UnityPair]>,
+}
+
+/// A row from `BTL_Pair`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct UnityPair {
+    /// The save file stores pair IDs as bytes
+    pub id: u8,
+    /// Character IDs for `CHR_PC`
+    pub characters: [usize; 2],
+}
+
+/// Reasons why a set of Unity Combo pairs is not valid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PairError {
+    /// The pair ID is not in `BTL_Pair`.
+    Unknown(u8),
+    /// The character is part of more than one pair.
+    SharedCharacter(usize),
+}
+
+impl PairRegistry {
+    pub fn new(pairs: impl IntoIterator<Item = UnityPair>) -> Self {
+        let mut pairs: Box<[UnityPair]> = pairs.into_iter().collect();
+        pairs.sort_unstable_by_key(|p| p.id);
+        Self { pairs }
+    }
+
+    pub fn get(&self, id: u8) -> Option<&UnityPair> {
+        self.pairs
+            .binary_search_by_key(&id, |p| p.id)
+            .ok()
+            .map(|i| &self.pairs[i])
+    }
+
+    pub fn pairs(&self) -> &[UnityPair] {
+        &self.pairs
+    }
+
+    /// Checks whether the given pair IDs can be used together.
+    ///
+    /// An ID of 0 means the pair slot is empty.
+    pub fn validate(&self, pair_ids: &[u8]) -> Result<(), PairError> {
+        let mut characters = Vec::new();
+        for &id in pair_ids.iter().filter(|&&id| id != 0) {
+            let pair = self.get(id).ok_or(PairError::Unknown(id))?;
+            for char_id in pair.characters {
+                if characters.contains(&char_id) {
+                    return Err(PairError::SharedCharacter(char_id));
+                }
+                characters.push(char_id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Changes a Unity Combo pair in the save file, if the resulting
+    /// pairs are valid. An ID of 0 clears the pair slot.
+    ///
+    /// ## Panics
+    /// Panics if the index is out of bounds.
+    pub fn set_pair(
+        &self,
+        save: &mut SaveData,
+        index: usize,
+        pair_id: u8,
+    ) -> Result<(), PairError> {
+        let mut pairs: [u8; DLC4_PAIR_MAX] = save.dlc4_pairs();
+        pairs[index] = pair_id;
+        self.validate(&pairs)?;
+        save.set_dlc4_pair(index, pair_id);
+        Ok(())
+    }
+}
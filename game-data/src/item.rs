@@ -4,7 +4,13 @@ use std::num::NonZeroUsize;
 
 use crate::lang::{FilterTable, Filterable, Id};
 use enum_map::{Enum, EnumArray, EnumMap};
-use recordkeeper::item::ItemType;
+use recordkeeper::{
+    character::class::CharacterClass,
+    chrono::ChronologicalOrder,
+    dlc::CRAFTED_ITEM_ID,
+    item::{edit::ItemEditor, DlcManualSlot, Inventory, ItemType},
+    SaveData,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::LanguageData;
@@ -13,6 +19,8 @@ use crate::LanguageData;
 pub struct ItemRegistry {
     items: EnumMap<Type, Vec<Item>>,
     gem_categories: Vec<GemCategory>,
+    /// The `ITM_` table Future Redeemed battle manuals are listed in
+    battle_manual_type: Option<Type>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Copy)]
@@ -24,17 +32,71 @@ pub struct Item {
     pub rarity: Rarity,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
 pub struct GemCategory {
     pub id: u32,
     pub name_id: usize,
+    /// `ITM_Gem` item IDs for each level, starting from level 1
+    pub gem_ids: Box<[u32]>,
+}
+
+/// A gem equipped on a class, see [`ItemRegistry::equipped_gems`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EquippedGem {
+    /// `ITM_Gem.Category`
+    pub category_id: u32,
+    /// The effective level, or [`None`] if there are no gems
+    /// for the category in the inventory.
+    pub level: Option<u32>,
+}
+
+/// Reasons why a gem level could not be set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GemError {
+    /// There is no gem category with the given ID.
+    UnknownCategory(u32),
+    /// The gem category has no gem for the given level.
+    InvalidLevel(u32),
+    /// There are no free gem slots in the inventory.
+    InventoryFull,
+    /// The inventory holds a gem of this higher level, which would stay the
+    /// effective level. Gems are only removed when requested.
+    HigherLevel(u32),
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Copy)]
 #[serde(try_from = "u32", into = "u32")]
 pub struct Type(pub ItemType);
 
-#[derive(Serialize, Deserialize, PartialEq, Copy, Clone)]
+/// Items to add with [`ItemRegistry::give_items`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GiveItemsFilter {
+    pub item_types: Vec<ItemType>,
+    /// Only add items with one of these rarities, or any rarity if [`None`]
+    pub rarities: Option<Vec<Rarity>>,
+}
+
+/// Changes made (or that would be made) by [`ItemRegistry::give_items`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GiveItemsReport {
+    pub grants: Vec<ItemGrant>,
+    /// Items that could not be added because there were no free slots
+    pub no_space: Vec<(ItemType, u32)>,
+}
+
+/// An item slot that was filled or refilled by [`ItemRegistry::give_items`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ItemGrant {
+    pub item_type: ItemType,
+    pub item_id: u32,
+    pub slot_index: usize,
+    /// The new item amount
+    pub amount: u16,
+    /// The amount before the change, 0 if the slot was empty
+    pub previous_amount: u16,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Copy, Clone, Debug)]
 pub enum Rarity {
     Common,
     Rare,
@@ -74,6 +136,22 @@ impl ItemRegistry {
         categories.insert(index, gem);
     }
 
+    /// Sets the item type of Future Redeemed battle manuals.
+    pub fn register_battle_manual_type(&mut self, item_type: ItemType) {
+        self.battle_manual_type = Some(Type(item_type));
+    }
+
+    /// Returns the item type of Future Redeemed battle manuals, or [`None`]
+    /// if it was not registered.
+    pub fn battle_manual_type(&self) -> Option<ItemType> {
+        self.battle_manual_type.map(|t| t.0)
+    }
+
+    /// Returns the item equipped in a Future Redeemed battle manual slot.
+    pub fn battle_manual(&self, manual: &DlcManualSlot) -> Option<&Item> {
+        self.get_item(self.battle_manual_type()?, manual.item_id().into())
+    }
+
     pub fn items_by_type(&self, item_type: ItemType) -> &[Item] {
         &self.items[Type(item_type)]
     }
@@ -88,6 +166,228 @@ impl ItemRegistry {
             .ok()
             .map(|i| &self.gem_categories[i])
     }
+
+    /// Returns the effective level of a gem category, i.e. the highest
+    /// level among the category's gems in the inventory.
+    ///
+    /// Returns [`None`] if the category is unknown or if there are no gems
+    /// for it in the inventory.
+    pub fn gem_level(&self, inventory: &Inventory, category_id: u32) -> Option<u32> {
+        let category = self.gem_category_by_id(category_id)?;
+        inventory
+            .slots(ItemType::Gem)
+            .iter()
+            .filter(|slot| slot.is_valid())
+            .filter_map(|slot| category.level_of(slot.item_id().into()))
+            .max()
+    }
+
+    /// Returns the gems equipped on a class, in slot order, along with
+    /// their effective levels.
+    pub fn equipped_gems(
+        &self,
+        class: &CharacterClass,
+        inventory: &Inventory,
+    ) -> Vec<Option<EquippedGem>> {
+        class
+            .gems()
+            .map(|slot| {
+                let category_id = u32::from(slot.get()?) + 1;
+                Some(EquippedGem {
+                    category_id,
+                    level: self.gem_level(inventory, category_id),
+                })
+            })
+            .collect()
+    }
+
+    /// Makes `level` the effective level of a gem category, by adding the
+    /// gem for that level to the inventory if needed.
+    ///
+    /// If the inventory holds gems of higher levels, they are removed when
+    /// `remove_higher` is `true`. Otherwise, [`GemError::HigherLevel`] is
+    /// returned and the inventory is left unchanged.
+    ///
+    /// Returns the item IDs of the removed gems, in inventory order.
+    pub fn set_gem_level(
+        &self,
+        save: &mut SaveData,
+        category_id: u32,
+        level: u32,
+        remove_higher: bool,
+    ) -> Result<Vec<u32>, GemError> {
+        let category = self
+            .gem_category_by_id(category_id)
+            .ok_or(GemError::UnknownCategory(category_id))?;
+        let gem_id = category
+            .gem_id(level)
+            .ok_or(GemError::InvalidLevel(level))?;
+
+        let gems = save.inventory.slots(ItemType::Gem);
+        let level_at = |i: usize| {
+            let slot = &gems[i];
+            slot.is_valid()
+                .then(|| category.level_of(slot.item_id().into()))
+                .flatten()
+        };
+        let present = (0..gems.len()).any(|i| level_at(i) == Some(level));
+        let higher = (0..gems.len())
+            .filter(|&i| level_at(i).is_some_and(|l| l > level))
+            .collect::<Vec<_>>();
+        if !remove_higher {
+            if let Some(max) = higher.iter().filter_map(|&i| level_at(i)).max() {
+                return Err(GemError::HigherLevel(max));
+            }
+        }
+        let free = gems.iter().position(|slot| !slot.is_valid());
+        if !present && free.is_none() && higher.is_empty() {
+            return Err(GemError::InventoryFull);
+        }
+
+        let removed = higher
+            .into_iter()
+            .map(|index| {
+                let item_id = save.inventory.slots(ItemType::Gem)[index].item_id();
+                ItemEditor::new(save, ItemType::Gem, index).clear();
+                u32::from(item_id)
+            })
+            .collect();
+        if !present {
+            let index = save
+                .inventory
+                .slots(ItemType::Gem)
+                .iter()
+                .position(|slot| !slot.is_valid())
+                .unwrap();
+            ItemEditor::new(save, ItemType::Gem, index)
+                .set_item_id(gem_id.try_into().unwrap())
+                .expect("gems have no crafting data");
+        }
+        Ok(removed)
+    }
+
+    /// Equips a gem category on a class, and makes `level` the category's
+    /// effective level. See [`ItemRegistry::set_gem_level`].
+    ///
+    /// Returns the item IDs of the removed gems.
+    ///
+    /// ## Panics
+    /// Panics if the character ID, class ID or slot index are out of bounds.
+    #[allow(clippy::too_many_arguments)]
+    pub fn equip_gem(
+        &self,
+        save: &mut SaveData,
+        char_id: usize,
+        class_id: usize,
+        slot: usize,
+        category_id: u32,
+        level: u32,
+        remove_higher: bool,
+    ) -> Result<Vec<u32>, GemError> {
+        let removed = self.set_gem_level(save, category_id, level, remove_higher)?;
+        let raw = (category_id - 1).try_into().unwrap();
+        save.characters[char_id.checked_sub(1).expect("invalid character ID")]
+            .class_data_mut(class_id)
+            .gem_slot_mut(slot)
+            .set(Some(raw));
+        Ok(removed)
+    }
+
+    /// Returns what [`ItemRegistry::give_items`] would do, without changing
+    /// the inventory.
+    ///
+    /// Collectopedia entries are skipped, as they are not stored in the
+    /// inventory.
+    pub fn plan_give_items(
+        &self,
+        inventory: &Inventory,
+        filter: &GiveItemsFilter,
+    ) -> GiveItemsReport {
+        let mut report = GiveItemsReport::default();
+        for &item_type in &filter.item_types {
+            if item_type == ItemType::Collectopedia {
+                continue;
+            }
+            let slots = inventory.slots(item_type);
+            let mut free = (0..slots.len()).filter(|&i| !slots[i].is_valid());
+
+            for item in self.items_by_type(item_type) {
+                if !filter.matches(item) {
+                    continue;
+                }
+                let amount = u16::try_from(item.amount_max.max(1)).unwrap_or(u16::MAX);
+                let existing = slots
+                    .iter()
+                    .position(|s| s.is_valid() && u32::from(s.item_id()) == item.id);
+                let (slot_index, previous_amount) = match existing {
+                    Some(i) if slots[i].amount() >= amount => continue,
+                    Some(i) => (i, slots[i].amount()),
+                    None => match free.next() {
+                        Some(i) => (i, 0),
+                        None => {
+                            report.no_space.push((item_type, item.id));
+                            continue;
+                        }
+                    },
+                };
+                report.grants.push(ItemGrant {
+                    item_type,
+                    item_id: item.id,
+                    slot_index,
+                    amount,
+                    previous_amount,
+                });
+            }
+        }
+        report
+    }
+
+    /// Adds every item that matches the filter to the inventory, at the item's
+    /// max amount.
+    ///
+    /// Items that are already in the inventory keep their slot, and only have
+    /// their amount raised. Other items are put in the first free slots, and are
+    /// registered in the item chronological order following item ID order.
+    ///
+    /// Unnamed items (usually unused), crafted accessories and Collectopedia
+    /// entries are skipped. Use [`ItemRegistry::plan_give_items`] for a dry run.
+    pub fn give_items(&self, save: &mut SaveData, filter: &GiveItemsFilter) -> GiveItemsReport {
+        let report = self.plan_give_items(&save.inventory, filter);
+        for grant in &report.grants {
+            let item_id = grant.item_id.try_into().unwrap();
+            let mut editor = ItemEditor::new(save, grant.item_type, grant.slot_index);
+            if grant.previous_amount == 0 {
+                editor
+                    .set_item_id(item_id)
+                    .expect("crafted accessories are skipped");
+            }
+            editor.set_amount(grant.amount);
+            if grant.previous_amount == 0 {
+                save.inventory.insert(item_id.into());
+            }
+        }
+        report
+    }
+}
+
+impl GiveItemsFilter {
+    /// Creates a filter for all items of the given types.
+    pub fn all(item_types: impl IntoIterator<Item = ItemType>) -> Self {
+        Self {
+            item_types: item_types.into_iter().collect(),
+            rarities: None,
+        }
+    }
+
+    fn matches(&self, item: &Item) -> bool {
+        let rarity = match &self.rarities {
+            Some(rarities) => rarities.contains(&item.rarity),
+            None => true,
+        };
+        let crafted =
+            item.item_type.0 == ItemType::Accessory && item.id == u32::from(CRAFTED_ITEM_ID);
+        rarity && !crafted && item.name_id.is_some()
+    }
 }
 
 impl ItemLanguageRegistry {
@@ -156,6 +456,29 @@ impl TryFrom<u32> for Type {
     }
 }
 
+impl GemCategory {
+    /// Returns the highest level available for the category.
+    pub fn max_level(&self) -> u32 {
+        self.gem_ids.len().try_into().unwrap()
+    }
+
+    /// Returns the gem item ID for the given level. (starts at 1)
+    pub fn gem_id(&self, level: u32) -> Option<u32> {
+        level
+            .checked_sub(1)
+            .and_then(|i| self.gem_ids.get(i as usize))
+            .copied()
+    }
+
+    /// Returns the level of the gem item, if it belongs to the category.
+    pub fn level_of(&self, item_id: u32) -> Option<u32> {
+        self.gem_ids
+            .iter()
+            .position(|&id| id == item_id)
+            .map(|i| i as u32 + 1)
+    }
+}
+
 impl Filterable for GemCategory {
     fn get_filter<'l>(&self, language: &'l LanguageData) -> Option<&'l crate::lang::FilterEntry> {
         language.items.tables[Type(ItemType::Gem)].get(self.name_id)
@@ -1,6 +1,7 @@
 use std::io::{Read, Write};
 
 use character::{CharacterData, CharacterLang};
+use collepedia::CollepediaRegistry;
 use dlc::{DlcData, DlcLang};
 use enemy::{EnemyLang, EnemyRegistry};
 use enhance::{EnhanceLang, EnhanceRegistry};
@@ -13,8 +14,11 @@ use quest::{QuestLang, QuestRegistry};
 use scenario::ScenarioRanges;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
+use system::{SystemLang, SystemRegistry};
 
+pub mod build;
 pub mod character;
+pub mod collepedia;
 pub mod dlc;
 pub mod enemy;
 pub mod enhance;
@@ -26,6 +30,7 @@ pub mod manual;
 pub mod ouroboros;
 pub mod quest;
 pub mod scenario;
+pub mod system;
 
 #[derive(Serialize, Deserialize)]
 pub struct GameData {
@@ -36,9 +41,11 @@ pub struct GameData {
     pub quests: QuestRegistry,
     pub characters: CharacterData,
     pub ouroboros: OuroborosRegistry,
+    pub collepedia: CollepediaRegistry,
     pub field: FieldRegistry,
     pub enemies: EnemyRegistry,
     pub formation: FormationData,
+    pub system: SystemRegistry,
 
     /// Manually inputted data, that can't be read
     /// from game files.
@@ -55,6 +62,7 @@ pub struct LanguageData {
     pub field: FieldLang,
     pub enemies: EnemyLang,
     pub formation: FormationLang,
+    pub system: SystemLang,
 }
 
 pub fn save_game_data(data: &GameData, mut writer: impl Write) -> Result<(), Box<dyn Error>> {
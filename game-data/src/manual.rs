@@ -29,7 +29,7 @@ pub struct Flags {
     pub dx_cylinder_level: Flag,
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub struct Flag {
     pub bits: usize,
     pub index: usize,
@@ -1,7 +1,13 @@
+use recordkeeper::{
+    character::{Ouroboros as SaveOuroboros, OUROBOROS_TREE_MAX},
+    flags::FlagType,
+    SaveData,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     lang::{Filterable, Id},
+    manual::{Flag, Flags},
     GameData, LanguageData,
 };
 
@@ -19,8 +25,18 @@ pub struct Ouroboros {
     pub tree_nodes: Box<[OuroTreeNode]>,
 }
 
+/// A node in an ouroboros skill tree (`CHR_UroSkillTree*`)
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct OuroTreeNode {
+    pub kind: OuroNodeKind,
+    /// SP needed to unlock the node
+    pub sp_cost: u32,
+    /// Nodes (by node ID) that must be unlocked before this one
+    pub prerequisites: Box<[usize]>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Copy, PartialOrd, Eq, Ord)]
-pub enum OuroTreeNode {
+pub enum OuroNodeKind {
     UnlockArt(usize),
     UnlockSkill(usize),
     UpgradeArt(usize),
@@ -43,20 +59,233 @@ impl OuroborosRegistry {
     }
 }
 
+/// Save file flags that control Interlink for an ouroboros pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterlinkFlags {
+    /// Set when the pair can Interlink
+    pub enable: Flag,
+    /// Set when the second linked skill slot is unlocked (`Flag_ShareSlot`)
+    pub share_slot: Flag,
+}
+
+/// Interlink status for an ouroboros pair, see [`InterlinkFlags::state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterlinkState {
+    pub enabled: bool,
+    pub shared_slot: bool,
+}
+
+/// Reasons why a skill tree operation failed, or why a skill tree is not consistent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OuroTreeError {
+    /// There is no node with the given ID in the tree.
+    UnknownNode(usize),
+    /// There is not enough SP to unlock the node and its locked prerequisites.
+    NotEnoughSp { needed: u32, available: u32 },
+    /// The node is unlocked, but one of its prerequisites is not.
+    MissingPrerequisite { node: usize, prerequisite: usize },
+}
+
 impl Ouroboros {
-    pub fn tree_nodes(&self) -> impl Iterator<Item = (usize, OuroTreeNode)> + '_ {
-        self.tree_nodes.iter().enumerate().map(|(i, n)| (i + 1, *n))
+    /// Returns the flags that control Interlink for this ouroboros.
+    ///
+    /// Noah has a dedicated enable flag, while other characters' flags are
+    /// consecutive, starting from Mio's.
+    pub fn interlink_flags(&self, flags: &Flags) -> InterlinkFlags {
+        let enable = if self.id == 1 {
+            flags.ouro_enable_noah
+        } else {
+            Flag {
+                bits: flags.ouro_enable.bits,
+                index: flags.ouro_enable.index + self.id - 2,
+            }
+        };
+        InterlinkFlags {
+            enable,
+            share_slot: Flag {
+                bits: 1,
+                index: self.share_slot_flag,
+            },
+        }
+    }
+
+    /// Returns the nodes in the skill tree, along with their node ID (1-based).
+    ///
+    /// The node ID - 1 is the node's index in the save file's
+    /// [`OuroborosTree`](recordkeeper::character::OuroborosTree).
+    pub fn tree_nodes(&self) -> impl Iterator<Item = (usize, &OuroTreeNode)> + '_ {
+        self.tree_nodes.iter().enumerate().map(|(i, n)| (i + 1, n))
+    }
+
+    pub fn tree_node(&self, id: usize) -> Option<&OuroTreeNode> {
+        id.checked_sub(1).and_then(|i| self.tree_nodes.get(i))
+    }
+
+    /// Returns the IDs of the nodes that must be unlocked (in order) to unlock
+    /// the given node, including the node itself. Nodes that are already unlocked
+    /// are skipped.
+    pub fn nodes_to_unlock(
+        &self,
+        ouroboros: &SaveOuroboros,
+        id: usize,
+    ) -> Result<Vec<usize>, OuroTreeError> {
+        let mut nodes = Vec::new();
+        self.push_locked(ouroboros, id, &mut nodes)?;
+        Ok(nodes)
+    }
+
+    /// Unlocks a node in the skill tree, along with any locked prerequisites.
+    ///
+    /// The SP cost of all newly unlocked nodes is deducted from the character's SP.
+    /// Nothing is changed if there isn't enough SP. Returns the amount of SP spent.
+    pub fn unlock_node(
+        &self,
+        ouroboros: &mut SaveOuroboros,
+        id: usize,
+    ) -> Result<u32, OuroTreeError> {
+        let nodes = self.nodes_to_unlock(ouroboros, id)?;
+        self.unlock_nodes(ouroboros, &nodes)
+    }
+
+    /// Unlocks all nodes in the skill tree, deducting their SP cost.
+    ///
+    /// Nothing is changed if there isn't enough SP. Returns the amount of SP spent.
+    pub fn unlock_all(&self, ouroboros: &mut SaveOuroboros) -> Result<u32, OuroTreeError> {
+        let mut nodes = Vec::new();
+        for (id, _) in self.tree_nodes() {
+            self.push_locked(ouroboros, id, &mut nodes)?;
+        }
+        self.unlock_nodes(ouroboros, &nodes)
+    }
+
+    /// Locks a node in the skill tree, giving back its SP cost.
+    ///
+    /// Unlocked nodes that depend on the node are also locked and refunded.
+    /// Returns the amount of SP refunded.
+    pub fn refund_node(
+        &self,
+        ouroboros: &mut SaveOuroboros,
+        id: usize,
+    ) -> Result<u32, OuroTreeError> {
+        let node = self.tree_node(id).ok_or(OuroTreeError::UnknownNode(id))?;
+        if !ouroboros.skill_tree.get(id - 1) {
+            return Ok(0);
+        }
+        ouroboros.skill_tree.set(id - 1, false);
+        ouroboros.sp = ouroboros.sp.saturating_add(node.sp_cost);
+        let mut refunded = node.sp_cost;
+
+        let dependents: Vec<_> = self
+            .tree_nodes()
+            .filter(|(_, n)| n.prerequisites.contains(&id))
+            .map(|(dep, _)| dep)
+            .collect();
+        for dep in dependents {
+            refunded += self.refund_node(ouroboros, dep)?;
+        }
+        Ok(refunded)
+    }
+
+    /// Checks whether the skill tree could have been unlocked in-game.
+    ///
+    /// Returns every unlocked node that is either unknown or is missing
+    /// one of its prerequisites.
+    pub fn check_tree(&self, ouroboros: &SaveOuroboros) -> Vec<OuroTreeError> {
+        let tree = &ouroboros.skill_tree;
+        let mut issues = Vec::new();
+        for index in tree.unlocked() {
+            let id = index + 1;
+            let Some(node) = self.tree_node(id) else {
+                issues.push(OuroTreeError::UnknownNode(id));
+                continue;
+            };
+            issues.extend(
+                node.prerequisites
+                    .iter()
+                    .filter(|&&pre| !(1..=OUROBOROS_TREE_MAX).contains(&pre) || !tree.get(pre - 1))
+                    .map(|&prerequisite| OuroTreeError::MissingPrerequisite {
+                        node: id,
+                        prerequisite,
+                    }),
+            );
+        }
+        issues
+    }
+
+    fn push_locked(
+        &self,
+        ouroboros: &SaveOuroboros,
+        id: usize,
+        nodes: &mut Vec<usize>,
+    ) -> Result<(), OuroTreeError> {
+        let node = self.tree_node(id).ok_or(OuroTreeError::UnknownNode(id))?;
+        if ouroboros.skill_tree.get(id - 1) || nodes.contains(&id) {
+            return Ok(());
+        }
+        for &pre in node.prerequisites.iter() {
+            self.push_locked(ouroboros, pre, nodes)?;
+        }
+        nodes.push(id);
+        Ok(())
+    }
+
+    fn unlock_nodes(
+        &self,
+        ouroboros: &mut SaveOuroboros,
+        nodes: &[usize],
+    ) -> Result<u32, OuroTreeError> {
+        let needed = nodes
+            .iter()
+            .filter_map(|&id| self.tree_node(id))
+            .map(|n| n.sp_cost)
+            .sum();
+        if needed > ouroboros.sp {
+            return Err(OuroTreeError::NotEnoughSp {
+                needed,
+                available: ouroboros.sp,
+            });
+        }
+        ouroboros.sp -= needed;
+        for &id in nodes {
+            ouroboros.skill_tree.set(id - 1, true);
+        }
+        Ok(needed)
+    }
+}
+
+impl InterlinkFlags {
+    pub fn state(&self, save: &SaveData) -> InterlinkState {
+        InterlinkState {
+            enabled: get_flag(save, self.enable),
+            shared_slot: get_flag(save, self.share_slot),
+        }
+    }
+
+    /// Enables or disables Interlink for the pair.
+    pub fn set_enabled(&self, save: &mut SaveData, enabled: bool) {
+        set_flag(save, self.enable, enabled);
+    }
+
+    /// Locks or unlocks the shared (second) linked skill slot.
+    pub fn set_shared_slot(&self, save: &mut SaveData, unlocked: bool) {
+        set_flag(save, self.share_slot, unlocked);
     }
 }
 
 impl OuroTreeNode {
+    pub fn get_param_name<'l>(&self, game: &GameData, lang: &'l LanguageData) -> Option<&'l str> {
+        self.kind.get_param_name(game, lang)
+    }
+}
+
+impl OuroNodeKind {
     pub fn get_param_name<'l>(&self, game: &GameData, lang: &'l LanguageData) -> Option<&'l str> {
         match self {
-            OuroTreeNode::UnlockArt(id) | Self::UpgradeArt(id) => game
+            Self::UnlockArt(id) | Self::UpgradeArt(id) => game
                 .characters
                 .get_art(*id)
                 .and_then(|a| a.get_filter(lang)),
-            OuroTreeNode::UnlockSkill(id) | OuroTreeNode::UpgradeSkill(id) => game
+            Self::UnlockSkill(id) | Self::UpgradeSkill(id) => game
                 .characters
                 .get_skill(*id)
                 .and_then(|s| s.get_filter(lang)),
@@ -79,3 +308,15 @@ impl Id for Ouroboros {
         self.id
     }
 }
+
+fn get_flag(save: &SaveData, flag: Flag) -> bool {
+    save.flags
+        .get(FlagType::from_bits(flag.bits), flag.index)
+        .expect("flag out of bounds")
+        != 0
+}
+
+fn set_flag(save: &mut SaveData, flag: Flag, value: bool) {
+    save.flags
+        .set(FlagType::from_bits(flag.bits), flag.index, value.into())
+}
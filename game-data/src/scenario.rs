@@ -35,16 +35,28 @@ impl ScenarioRanges {
     }
 
     pub fn get_chapter_by_scenario(&self, scenario_flag: u16, dlc4: bool) -> Option<u32> {
-        let ranges = if dlc4 {
-            &self.ranges_base
-        } else {
-            &self.ranges_dlc4
-        };
+        let ranges = self.ranges(dlc4);
         ranges
             .binary_search_by(|r| range_cmp(&r.range, scenario_flag))
             .ok()
             .map(|i| ranges[i].chapter)
     }
+
+    /// Returns whether the scenario flag has reached the end of the story,
+    /// i.e. the last scenario of the final chapter or later.
+    pub fn is_story_complete(&self, scenario_flag: u16, dlc4: bool) -> bool {
+        self.ranges(dlc4)
+            .last()
+            .is_some_and(|last| scenario_flag >= *last.range.end())
+    }
+
+    fn ranges(&self, dlc4: bool) -> &[ScenarioRange] {
+        if dlc4 {
+            &self.ranges_dlc4
+        } else {
+            &self.ranges_base
+        }
+    }
 }
 
 fn range_cmp(range: &RangeInclusive<u16>, item: u16) -> Ordering {
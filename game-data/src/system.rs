@@ -0,0 +1,195 @@
+//! Data for the system file (`bf3system00.sav`)
+
+use recordkeeper::{flags::FlagType, SaveFile, SystemData, SystemFile, SystemFlag};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    lang::{Id, Nameable, TextEntry, TextTable},
+    manual::{Flag, Flags},
+    scenario::ScenarioRanges,
+    LanguageData,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct SystemRegistry {
+    options: Vec<GameOption>,
+    events: Vec<TheaterEvent>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SystemLang {
+    pub options: TextTable,
+    pub events: TextTable,
+}
+
+/// A global setting, from one of the `MNU_option_*` tables.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct GameOption {
+    /// Index into the system file's settings
+    pub option_id: usize,
+    /// Name of the `MNU_option_*` table that defines the option
+    pub table: Box<str>,
+    /// Row ID in the source table
+    pub row_id: usize,
+    pub name_id: Option<usize>,
+}
+
+/// An event that can be watched in the Event Theater.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct TheaterEvent {
+    /// Index into the system file's Event Theater flags
+    pub id: usize,
+    pub chapter: u32,
+    pub name_id: Option<usize>,
+}
+
+/// Changes made by [`sync_clear_flags`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClearFlagSync {
+    /// System flags that were set
+    pub system_flags: Vec<SystemFlag>,
+    /// Whether the save's Future Redeemed completion flag was set
+    pub save_fr_complete: bool,
+}
+
+/// Reconciles progress flags between a save file and the system file.
+///
+/// * If the save has cleared the main game (or Future Redeemed, for DLC4 saves),
+///   the matching system clear flag is set.
+/// * If the save is on New Game Plus and the story has been completed again in
+///   the current cycle, the NG+ variant is set as well. The clear flag alone is
+///   not enough, as NG+ saves carry it over from the previous cycle.
+/// * If Future Redeemed has been cleared according to the system file, the
+///   base game save gets its `fr_complete` flag, like the game does when loading it.
+///
+/// Flags are only ever set, never cleared.
+pub fn sync_clear_flags(
+    flags: &Flags,
+    scenarios: &ScenarioRanges,
+    save: &mut SaveFile,
+    system: &mut SystemFile,
+) -> ClearFlagSync {
+    let save = save.save_mut();
+    let system = system.save_mut();
+    let mut sync = ClearFlagSync::default();
+
+    let is_dlc4 = save.is_dlc4();
+    let get_flag = |flag: Flag| save.flags.get(FlagType::from_bits(flag.bits), flag.index);
+    let cleared = get_flag(flags.game_clear).is_some_and(|v| v != 0);
+    let ngp = get_flag(flags.new_game_plus).is_some_and(|v| v != 0);
+    let ngp_cleared = ngp
+        && get_flag(flags.scenario).is_some_and(|scenario| {
+            scenarios.is_story_complete(scenario.try_into().unwrap_or(u16::MAX), is_dlc4)
+        });
+
+    if cleared {
+        let to_set: &[SystemFlag] = match (is_dlc4, ngp_cleared) {
+            (false, false) => &[SystemFlag::MainGameClear],
+            (false, true) => &[SystemFlag::MainGameClear, SystemFlag::MainGameClearNgp],
+            (true, false) => &[SystemFlag::Dlc4Clear],
+            (true, true) => &[SystemFlag::Dlc4Clear, SystemFlag::Dlc4ClearNgp],
+        };
+        for &flag in to_set {
+            if !system.is_flag_set(flag) {
+                system.set_flag(flag, true);
+                sync.system_flags.push(flag);
+            }
+        }
+    }
+
+    let fr_complete = flags.fr_complete;
+    let fr_complete_type = FlagType::from_bits(fr_complete.bits);
+    if !is_dlc4
+        && system.is_flag_set(SystemFlag::Dlc4Clear)
+        && save.flags.get(fr_complete_type, fr_complete.index) == Some(0)
+    {
+        save.flags.set(fr_complete_type, fr_complete.index, 1);
+        sync.save_fr_complete = true;
+    }
+
+    sync
+}
+
+impl SystemRegistry {
+    pub fn new(
+        options: impl IntoIterator<Item = GameOption>,
+        events: impl IntoIterator<Item = TheaterEvent>,
+    ) -> Self {
+        let mut options: Vec<_> = options.into_iter().collect();
+        options.sort_unstable_by(|a, b| a.table.cmp(&b.table).then(a.row_id.cmp(&b.row_id)));
+        let mut events: Vec<_> = events.into_iter().collect();
+        events.sort_unstable_by_key(|e| e.id);
+        Self { options, events }
+    }
+
+    /// Returns the Event Theater event with the given ID.
+    pub fn event(&self, id: usize) -> Option<&TheaterEvent> {
+        self.events
+            .binary_search_by_key(&id, |e| e.id)
+            .ok()
+            .map(|i| &self.events[i])
+    }
+
+    /// Returns all Event Theater events, sorted by ID.
+    pub fn events(&self) -> &[TheaterEvent] {
+        &self.events
+    }
+
+    /// Returns the option defined in the given table row.
+    ///
+    /// `table` is the full table name, e.g. `MNU_option_camera`.
+    pub fn option(&self, table: &str, row_id: usize) -> Option<&GameOption> {
+        self.options
+            .iter()
+            .find(|opt| &*opt.table == table && opt.row_id == row_id)
+    }
+
+    /// Returns all options defined in the given table, in row order.
+    pub fn options_in<'a>(&'a self, table: &'a str) -> impl Iterator<Item = &'a GameOption> {
+        self.options.iter().filter(move |opt| &*opt.table == table)
+    }
+
+    pub fn options(&self) -> &[GameOption] {
+        &self.options
+    }
+}
+
+impl GameOption {
+    /// Returns the current value for the option in the system file.
+    pub fn get(&self, system: &SystemData) -> u16 {
+        system.get_setting(self.option_id)
+    }
+
+    /// Updates the value for the option in the system file.
+    pub fn set(&self, system: &mut SystemData, value: u16) {
+        system.set_setting(self.option_id, value)
+    }
+}
+
+impl TheaterEvent {
+    pub fn is_unlocked(&self, system: &SystemData) -> bool {
+        system.is_event_unlocked(self.id)
+    }
+
+    pub fn set_unlocked(&self, system: &mut SystemData, unlocked: bool) {
+        system.set_event_unlocked(self.id, unlocked)
+    }
+}
+
+impl Nameable for TheaterEvent {
+    fn get_name<'l>(&self, language: &'l LanguageData) -> Option<&'l TextEntry> {
+        self.name_id.and_then(|id| language.system.events.get(id))
+    }
+}
+
+impl Id for TheaterEvent {
+    fn id(&self) -> usize {
+        self.id
+    }
+}
+
+impl Nameable for GameOption {
+    fn get_name<'l>(&self, language: &'l LanguageData) -> Option<&'l TextEntry> {
+        self.name_id.and_then(|id| language.system.options.get(id))
+    }
+}
@@ -0,0 +1,203 @@
+use game_data::{
+    build::{AccessoryEntry, BuildEntry, BuildIssue, ClassBuild},
+    character::CharacterData,
+    item::ItemRegistry,
+};
+use recordkeeper::{
+    character::{
+        class::CharacterClass,
+        formation::{CharacterFormation, FormationName, PartyFormation},
+    },
+    item::ItemType,
+    SaveFile,
+};
+
+mod common;
+
+const SRC: &[u8] = include_bytes!("../../lib/tests/res/save-ch5-v10.sav");
+
+fn characters() -> CharacterData {
+    common::character_data(vec![], Default::default(), vec![])
+}
+
+/// Arts, skills and gems of a class, in slot order
+fn equipment(class: &CharacterClass) -> Vec<Option<u16>> {
+    let arts = class.arts().map(|s| s.get());
+    let skills = class.skills().map(|s| s.get());
+    let gems = class.gems().map(|s| s.get().map(u16::from));
+    arts.chain(skills).chain(gems).collect()
+}
+
+#[test]
+pub fn export_class() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save();
+    let class = save.characters[0].class_data(18);
+
+    let build = ClassBuild::from_class(
+        18,
+        class,
+        save,
+        &characters(),
+        &ItemRegistry::default(),
+        None,
+    );
+    assert_eq!(BuildEntry { id: 18, name: None }, build.class);
+    // Gems are exported as categories, i.e. raw value + 1
+    let gems = build.gems.iter().map(|g| g.as_ref().map(|g| g.id));
+    assert_eq!(
+        vec![Some(18), Some(16), Some(20)],
+        gems.take(3).collect::<Vec<_>>()
+    );
+    // Accessories are exported as item IDs
+    let accessories = save.inventory.slots(ItemType::Accessory);
+    for (slot, entry) in class.accessories().zip(&build.accessories) {
+        let item_id = slot
+            .get()
+            .map(|a| usize::from(accessories[usize::from(a.slot_index())].item_id()));
+        assert_eq!(item_id, entry.as_ref().map(|e| e.item.id));
+    }
+}
+
+#[test]
+pub fn export_formation() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save();
+    let formation = PartyFormation::from_save(save, FormationName::default());
+    let (characters, items) = (characters(), ItemRegistry::default());
+
+    let build = ClassBuild::from_formation(
+        formation.character(1).unwrap(),
+        save,
+        &characters,
+        &items,
+        None,
+    );
+    let expected = ClassBuild::from_class(
+        18,
+        save.characters[0].class_data(18),
+        save,
+        &characters,
+        &items,
+        None,
+    );
+    assert_eq!(Some(expected), build);
+
+    let mut slot = CharacterFormation::from_save(&save.characters[0], 1);
+    slot.current_class = 0;
+    assert_eq!(
+        None,
+        ClassBuild::from_formation(&slot, save, &characters, &items, None)
+    );
+}
+
+#[test]
+pub fn import_class() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let class = *save.characters[0].class_data(18);
+    let mut build = ClassBuild::from_class(
+        18,
+        &class,
+        save,
+        &characters(),
+        &ItemRegistry::default(),
+        None,
+    );
+
+    let edited = save.characters[0].class_data_mut(18);
+    edited.arts_mut().for_each(|mut s| s.set(None));
+    edited.gems_mut().for_each(|mut s| s.set(None));
+    assert!(build.apply_to(save, 1).is_empty());
+    assert_eq!(
+        equipment(&class),
+        equipment(save.characters[0].class_data(18))
+    );
+
+    // Accessory 1999 is not in the inventory
+    let accessories = save.inventory.slots(ItemType::Accessory);
+    assert!(!accessories
+        .iter()
+        .any(|s| s.is_valid() && s.item_id() == 1999));
+    build.accessories[0] = Some(AccessoryEntry {
+        item: BuildEntry {
+            id: 1999,
+            name: None,
+        },
+        hero: None,
+    });
+    assert_eq!(
+        vec![BuildIssue::MissingAccessory {
+            slot: 0,
+            item_id: 1999
+        }],
+        build.apply_to(save, 1)
+    );
+    assert!(save.characters[0]
+        .class_data(18)
+        .accessory_slot(0)
+        .get()
+        .is_none());
+}
+
+#[test]
+pub fn import_hero_class() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    // Hero accessories don't refer to the inventory
+    let class = *save.characters[6].class_data(7);
+    let build = ClassBuild::from_class(
+        7,
+        &class,
+        save,
+        &characters(),
+        &ItemRegistry::default(),
+        None,
+    );
+    assert_eq!(Some(56), build.accessories[0].as_ref().map(|a| a.item.id));
+    assert!(build.accessories[0].as_ref().unwrap().hero.is_some());
+
+    let json = serde_json::to_string(&build).unwrap();
+    let build: ClassBuild = serde_json::from_str(&json).unwrap();
+    let edited = save.characters[6].class_data_mut(7);
+    edited.accessories_mut().for_each(|mut s| s.set(None));
+    assert!(build.apply_to(save, 7).is_empty());
+    let accessories = |class: &CharacterClass| {
+        class
+            .accessories()
+            .map(|s| {
+                s.get()
+                    .map(|a| (a.bdat_id(), a.slot_index(), a.raw_item_type()))
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        accessories(&class),
+        accessories(save.characters[6].class_data(7))
+    );
+}
+
+#[test]
+pub fn build_json_round_trip() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save();
+    let mut build = ClassBuild::from_class(
+        18,
+        save.characters[0].class_data(18),
+        save,
+        &characters(),
+        &ItemRegistry::default(),
+        None,
+    );
+    build.class.name = Some("Swordfighter".to_string());
+
+    let json = serde_json::to_string_pretty(&build).unwrap();
+    assert_eq!(build, serde_json::from_str::<ClassBuild>(&json).unwrap());
+    // Names are optional
+    let build: ClassBuild = serde_json::from_str(
+        r#"{"class":{"id":1},"arts":[null],"skills":[],"gems":[{"id":2}],"accessories":[]}"#,
+    )
+    .unwrap();
+    assert_eq!(None, build.class.name);
+    assert_eq!(2, build.gems[0].as_ref().unwrap().id);
+}
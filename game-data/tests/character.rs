@@ -0,0 +1,129 @@
+use game_data::character::{CharacterData, ClassGrowth, Costume, CostumeError};
+use recordkeeper::{SaveFile, SystemFile};
+
+mod common;
+
+const SRC: &[u8] = include_bytes!("../../lib/tests/res/save-ch5-v10.sav");
+const SYSTEM: &[u8] = include_bytes!("../../lib/tests/res/system.sav");
+
+fn costumes(char_id: usize, ids: &[usize]) -> CharacterData {
+    let mut costumes: [Vec<Costume>; 6] = Default::default();
+    costumes[char_id - 1] = ids.iter().map(|&id| Costume { id, name_id: 1 }).collect();
+    common::character_data(vec![], costumes, vec![])
+}
+
+#[test]
+pub fn costume_status() {
+    let mut system = SystemFile::from_bytes(SYSTEM).unwrap();
+    let system = system.save_mut();
+
+    let data = costumes(6, &[1]);
+    let status = data.costume_status(6, system).collect::<Vec<_>>();
+    assert_eq!(1, status.len());
+    assert_eq!(Ok(true), status[0].1);
+    // Other characters have no costumes
+    assert_eq!(0, data.costume_status(1, system).count());
+
+    let data = costumes(1, &[29, 60]);
+    let status = data
+        .costume_status(1, system)
+        .map(|(c, s)| (c.id, s))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![(29, Ok(false)), (60, Err(CostumeError::OutOfBounds(60)))],
+        status
+    );
+}
+
+#[test]
+pub fn unlock_costumes() {
+    let mut system = SystemFile::from_bytes(SYSTEM).unwrap();
+    let system = system.save_mut();
+
+    // Nothing is unlocked if any costume is out of bounds
+    let data = costumes(1, &[29, 60]);
+    assert_eq!(
+        Err(CostumeError::OutOfBounds(60)),
+        data.unlock_costumes(1, system)
+    );
+    assert!(!system.is_costume_unlocked(29, 1));
+
+    let data = costumes(1, &[0, 29]);
+    data.unlock_costumes(1, system).unwrap();
+    assert!(system.is_costume_unlocked(0, 1));
+    assert!(system.is_costume_unlocked(29, 1));
+    assert!(data.costume_status(1, system).all(|(_, s)| s == Ok(true)));
+}
+
+#[test]
+pub fn validate_costume() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let system = SystemFile::from_bytes(SYSTEM).unwrap();
+    let system = system.save();
+
+    let data = costumes(1, &[1, 29, 60]);
+    // Default outfit
+    assert_eq!(Ok(()), data.validate_costume(1, save, system));
+
+    let mut validate = |costume_id| {
+        save.characters[0].costume_id = costume_id;
+        data.validate_costume(1, save, system)
+    };
+    assert_eq!(Ok(()), validate(1));
+    assert_eq!(Err(CostumeError::Locked(29)), validate(29));
+    assert_eq!(Err(CostumeError::Unknown(5)), validate(5));
+    assert_eq!(Err(CostumeError::OutOfBounds(60)), validate(60));
+}
+
+#[test]
+pub fn master_all_classes() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let growth = (1..=43)
+        .map(|class_id| ClassGrowth {
+            class_id,
+            rank_cp: vec![0, 100, 200].into(),
+            unlock_points: 0,
+            arts: Box::default(),
+            skills: Box::default(),
+        })
+        .collect::<Vec<_>>();
+    let data = common::character_data(common::save_characters(save), Default::default(), growth);
+
+    let dlc4_classes = (1..=43)
+        .filter(|&class_id| data.is_dlc4_class(class_id))
+        .collect::<Vec<_>>();
+    assert!(!dlc4_classes.is_empty());
+    assert!(!data.is_dlc4_class(10));
+    let dlc4_characters = data
+        .characters()
+        .iter()
+        .filter(|c| c.is_dlc4())
+        .map(|c| {
+            let rank = save.characters[c.id - 1].class_data(c.default_class).rank();
+            (c.id, c.default_class, rank)
+        })
+        .collect::<Vec<_>>();
+
+    data.master_all_classes(save);
+
+    // Base game save: main characters don't get Future Redeemed classes
+    for char_id in 1..=6 {
+        let character = &save.characters[char_id - 1];
+        assert_eq!(3, character.class_data(10).rank());
+        assert!(dlc4_classes
+            .iter()
+            .all(|&class_id| character.class_data(class_id).rank() == 0));
+    }
+    // Heroes only get their own class mastered
+    assert_eq!(3, save.characters[10].class_data(10).rank());
+    assert_eq!(0, save.characters[10].class_data(11).rank());
+    // Future Redeemed characters are left as-is
+    for (char_id, class_id, rank) in dlc4_characters {
+        assert_eq!(
+            rank,
+            save.characters[char_id - 1].class_data(class_id).rank()
+        );
+    }
+}
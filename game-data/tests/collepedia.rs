@@ -0,0 +1,80 @@
+use game_data::collepedia::{
+    CollepediaEntry, CollepediaError, CollepediaRegistry, CollepediaReward, RegionProgress,
+};
+use recordkeeper::{item::ItemType, SaveFile};
+
+mod common;
+
+const SRC: &[u8] = include_bytes!("../../lib/tests/res/save-ch5-v10.sav");
+
+fn registry() -> CollepediaRegistry {
+    let entries = (0..4).map(|i| CollepediaEntry {
+        id: 4001 + i as u32,
+        region: 1 + i / 3,
+        item_id: 2001 + i as u32,
+        amount: 1,
+        flag: common::UNUSED_FLAG_BASE + i,
+    });
+    let rewards = (0..2).map(|i| CollepediaReward {
+        id: 1 + i as u32,
+        region: 1,
+        required: 2 + i,
+        item_id: 16001,
+        amount: 1,
+        flag: common::UNUSED_FLAG_BASE + 4 + i,
+    });
+    CollepediaRegistry::new(entries, rewards)
+}
+
+#[test]
+pub fn collepedia_progress() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let registry = registry();
+
+    assert!(save.inventory.slots(ItemType::Collectopedia).is_empty());
+    assert_eq!(vec![1, 2], registry.regions());
+    assert_eq!(
+        RegionProgress {
+            turned_in: 0,
+            total: 3
+        },
+        registry.region_progress(save, 1)
+    );
+
+    registry.set_turned_in(save, 4001, true).unwrap();
+    assert_eq!(
+        Err(CollepediaError::NotEnoughEntries {
+            required: 2,
+            turned_in: 1
+        }),
+        registry.set_claimed(save, 1, true)
+    );
+
+    registry.set_turned_in(save, 4002, true).unwrap();
+    registry.set_claimed(save, 1, true).unwrap();
+    assert!(registry.get_reward(1).unwrap().is_claimed(save));
+    // Other regions are not affected
+    assert_eq!(0, registry.region_progress(save, 2).turned_in);
+
+    // Rewards that are no longer available are un-claimed
+    registry.set_turned_in(save, 4001, false).unwrap();
+    assert!(!registry.get_reward(1).unwrap().is_claimed(save));
+
+    registry.complete_region(save, 1);
+    assert_eq!(
+        RegionProgress {
+            turned_in: 3,
+            total: 3
+        },
+        registry.region_progress(save, 1)
+    );
+    assert!(registry
+        .region_rewards(1)
+        .all(|reward| reward.is_claimed(save)));
+
+    assert_eq!(
+        Err(CollepediaError::UnknownEntry(4100)),
+        registry.set_turned_in(save, 4100, true)
+    );
+}
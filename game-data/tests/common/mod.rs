@@ -0,0 +1,55 @@
+#![allow(dead_code)]
+
+use std::iter::empty;
+
+use game_data::{
+    character::{Character, CharacterData, ClassGrowth, Costume, GrowthTables},
+    manual::Flags,
+};
+use recordkeeper::SaveData;
+
+/// First of a range of flags that are unset in the sample save. Flag IDs that
+/// normally come from BDAT tables (which are not available to tests) are
+/// allocated from here.
+pub const UNUSED_FLAG_BASE: usize = 30000;
+
+/// Flags from `app-builder/res/flags.json`, as they are shipped in the game data.
+pub fn manual_flags() -> Flags {
+    serde_json::from_str(include_str!("../../../app-builder/res/flags.json")).unwrap()
+}
+
+/// Characters from a save file. As `CHR_PC` is not available to tests, each
+/// character's default class is the class they have selected in the save.
+pub fn save_characters(save: &SaveData) -> Vec<Character> {
+    save.characters
+        .iter()
+        .enumerate()
+        .map(|(i, character)| Character {
+            id: i + 1,
+            name_id: 0,
+            default_class: character.selected_class.into(),
+            pow_augment: None,
+        })
+        .collect()
+}
+
+/// Character data with only characters, costumes and class growth tables.
+pub fn character_data(
+    characters: Vec<Character>,
+    costumes: [Vec<Costume>; 6],
+    class_growth: Vec<ClassGrowth>,
+) -> CharacterData {
+    CharacterData::new(
+        characters,
+        empty(),
+        empty(),
+        empty(),
+        empty(),
+        costumes,
+        GrowthTables {
+            level_exp: vec![],
+            class_growth,
+        },
+        empty(),
+    )
+}
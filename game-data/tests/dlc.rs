@@ -0,0 +1,88 @@
+use game_data::dlc::pair::{PairError, PairRegistry, UnityPair};
+use recordkeeper::{dlc::DLC4_PAIR_MAX, SaveFile};
+
+const SRC: &[u8] = include_bytes!("../../lib/tests/res/save-ch5-v10.sav");
+
+/// Pairs 1-4: (36, 37), (38, 39), (40, 41), (36, 42)
+fn pairs() -> PairRegistry {
+    PairRegistry::new([
+        UnityPair {
+            id: 4,
+            characters: [36, 42],
+        },
+        UnityPair {
+            id: 1,
+            characters: [36, 37],
+        },
+        UnityPair {
+            id: 2,
+            characters: [38, 39],
+        },
+        UnityPair {
+            id: 3,
+            characters: [40, 41],
+        },
+    ])
+}
+
+#[test]
+pub fn validate_pairs() {
+    let registry = pairs();
+    assert_eq!(Some(4), registry.get(4).map(|p| p.id));
+    assert_eq!(Ok(()), registry.validate(&[1, 2, 3]));
+    assert_eq!(Ok(()), registry.validate(&[0, 0, 0]));
+    assert_eq!(Err(PairError::Unknown(5)), registry.validate(&[1, 5, 0]));
+    assert_eq!(
+        Err(PairError::SharedCharacter(36)),
+        registry.validate(&[1, 2, 4])
+    );
+    // Empty slots are not considered duplicates
+    assert_eq!(Ok(()), registry.validate(&[0, 1, 0]));
+}
+
+#[test]
+pub fn set_pairs() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let registry = pairs();
+    assert_eq!([0; DLC4_PAIR_MAX], save.dlc4_pairs());
+
+    registry.set_pair(save, 0, 1).unwrap();
+    registry.set_pair(save, 2, 3).unwrap();
+    assert_eq!([1, 0, 3], save.dlc4_pairs());
+
+    // Invalid pairs leave the save unchanged
+    assert_eq!(
+        Err(PairError::SharedCharacter(36)),
+        registry.set_pair(save, 1, 4)
+    );
+    assert_eq!(Err(PairError::Unknown(9)), registry.set_pair(save, 1, 9));
+    assert_eq!([1, 0, 3], save.dlc4_pairs());
+
+    // Replacing a pair frees its characters
+    registry.set_pair(save, 0, 4).unwrap();
+    registry.set_pair(save, 2, 0).unwrap();
+    assert_eq!([4, 0, 0], save.dlc4_pairs());
+
+    // Unchecked
+    save.set_dlc4_pair(1, 4);
+    assert_eq!([4, 4, 0], save.dlc4_pairs());
+    assert_eq!(
+        Err(PairError::SharedCharacter(36)),
+        registry.validate(&save.dlc4_pairs())
+    );
+}
+
+#[test]
+#[should_panic]
+pub fn set_pair_out_of_bounds() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    pairs().set_pair(save.save_mut(), DLC4_PAIR_MAX, 1).unwrap();
+}
+
+#[test]
+#[should_panic]
+pub fn set_dlc4_pair_out_of_bounds() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    save.save_mut().set_dlc4_pair(DLC4_PAIR_MAX, 1);
+}
@@ -0,0 +1,205 @@
+use std::num::NonZeroUsize;
+
+use game_data::item::{
+    GemCategory, GemError, GiveItemsFilter, GiveItemsReport, Item, ItemRegistry, Rarity, Type,
+};
+use recordkeeper::{
+    chrono::ChronologicalOrder,
+    item::{edit::ItemEditor, ItemType},
+    SaveFile,
+};
+
+const SRC: &[u8] = include_bytes!("../../lib/tests/res/save-ch5-v10.sav");
+
+fn key_items(ids: std::ops::Range<u32>) -> ItemRegistry {
+    let mut registry = ItemRegistry::default();
+    for id in ids {
+        registry.register_item(Item {
+            id,
+            name_id: NonZeroUsize::new(1),
+            item_type: Type(ItemType::Precious),
+            amount_max: 3,
+            rarity: if id % 2 == 0 {
+                Rarity::Rare
+            } else {
+                Rarity::Common
+            },
+        });
+    }
+    registry
+}
+
+/// Gem categories with 10 levels each. Gems of category 1 have IDs
+/// 12001-12010, and so on.
+fn gems(categories: std::ops::RangeInclusive<u32>) -> ItemRegistry {
+    let mut registry = ItemRegistry::default();
+    for id in categories {
+        let first = 12001 + (id - 1) * 10;
+        registry.register_gem_category(GemCategory {
+            id,
+            name_id: 1,
+            gem_ids: (first..first + 10).collect(),
+        });
+    }
+    registry
+}
+
+#[test]
+pub fn give_all_items() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    // More items than there are key item slots (200)
+    let registry = key_items(16001..16300);
+    let filter = GiveItemsFilter::all([ItemType::Precious]);
+
+    let plan = registry.plan_give_items(&save.inventory, &filter);
+    let report = registry.give_items(save, &filter);
+    assert_eq!(plan, report);
+    assert!(!report.no_space.is_empty());
+
+    let slots = save.inventory.slots(ItemType::Precious);
+    assert!(slots.iter().all(|s| s.is_valid()));
+    for grant in &report.grants {
+        let slot = &slots[grant.slot_index];
+        assert_eq!(grant.item_id, u32::from(slot.item_id()));
+        assert_eq!(3, slot.amount());
+    }
+
+    // New items are registered in item ID order
+    let mut new_items = report.grants.iter().filter(|g| g.previous_amount == 0);
+    let first = new_items.next().unwrap().item_id as usize;
+    let last = new_items.next_back().unwrap().item_id as usize;
+    assert!(save.inventory.cmp_entries(last, first).is_lt());
+
+    // Nothing left to add
+    let plan = registry.plan_give_items(&save.inventory, &filter);
+    assert!(plan.grants.is_empty());
+}
+
+#[test]
+pub fn give_collectopedia() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let mut registry = ItemRegistry::default();
+    registry.register_item(Item {
+        id: 4001,
+        name_id: NonZeroUsize::new(1),
+        item_type: Type(ItemType::Collectopedia),
+        amount_max: 1,
+        rarity: Rarity::Common,
+    });
+
+    // Collectopedia entries are not in the inventory, so they are skipped
+    // instead of being reported as not fitting
+    let filter = GiveItemsFilter::all([ItemType::Collectopedia]);
+    assert_eq!(
+        GiveItemsReport::default(),
+        registry.give_items(save, &filter)
+    );
+}
+
+#[test]
+pub fn give_items_by_rarity() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let registry = key_items(16201..16211);
+    let filter = GiveItemsFilter {
+        item_types: vec![ItemType::Precious],
+        rarities: Some(vec![Rarity::Rare]),
+    };
+
+    let plan = registry.plan_give_items(&save.save().inventory, &filter);
+    assert_eq!(5, plan.grants.len());
+    assert!(plan.grants.iter().all(|g| g.item_id % 2 == 0));
+}
+
+#[test]
+pub fn gem_levels() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let registry = gems(1..=30);
+
+    // Gems 12001, 12003, 12004 and 12005 are in the inventory
+    assert_eq!(Some(5), registry.gem_level(&save.inventory, 1));
+    assert_eq!(None, registry.gem_level(&save.inventory, 30));
+    assert_eq!(None, registry.gem_level(&save.inventory, 31));
+
+    // Higher levels are only removed on request
+    assert_eq!(
+        Err(GemError::HigherLevel(5)),
+        registry.set_gem_level(save, 1, 3, false)
+    );
+    assert_eq!(Some(5), registry.gem_level(&save.inventory, 1));
+    let mut removed = registry.set_gem_level(save, 1, 3, true).unwrap();
+    removed.sort_unstable();
+    assert_eq!(vec![12004, 12005], removed);
+    assert_eq!(Some(3), registry.gem_level(&save.inventory, 1));
+
+    assert_eq!(Ok(vec![]), registry.set_gem_level(save, 1, 7, false));
+    assert_eq!(Some(7), registry.gem_level(&save.inventory, 1));
+}
+
+#[test]
+pub fn gem_errors() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let registry = gems(1..=30);
+
+    assert_eq!(
+        Err(GemError::UnknownCategory(31)),
+        registry.set_gem_level(save, 31, 1, false)
+    );
+    assert_eq!(
+        Err(GemError::InvalidLevel(11)),
+        registry.set_gem_level(save, 1, 11, false)
+    );
+    assert_eq!(
+        Err(GemError::InvalidLevel(0)),
+        registry.set_gem_level(save, 1, 0, false)
+    );
+
+    let free = save
+        .inventory
+        .slots(ItemType::Gem)
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| !s.is_valid())
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    for index in free {
+        let mut editor = ItemEditor::new(save, ItemType::Gem, index);
+        editor.set_item_id(13001).unwrap();
+        editor.set_amount(1);
+    }
+    assert_eq!(
+        Err(GemError::InventoryFull),
+        registry.set_gem_level(save, 30, 1, false)
+    );
+    assert_eq!(None, registry.gem_level(&save.inventory, 30));
+    // Removing higher levels frees up a slot
+    let mut removed = registry.set_gem_level(save, 1, 2, true).unwrap();
+    removed.sort_unstable();
+    assert_eq!(vec![12003, 12004, 12005], removed);
+    assert_eq!(Some(2), registry.gem_level(&save.inventory, 1));
+}
+
+#[test]
+pub fn equip_gem() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let registry = gems(1..=30);
+
+    assert_eq!(
+        Err(GemError::InvalidLevel(11)),
+        registry.equip_gem(save, 1, 18, 3, 30, 11, false)
+    );
+    assert_eq!(None, save.characters[0].class_data(18).gem_slot(3).get());
+
+    assert_eq!(Ok(vec![]), registry.equip_gem(save, 1, 18, 3, 30, 2, false));
+    assert_eq!(
+        Some(29),
+        save.characters[0].class_data(18).gem_slot(3).get()
+    );
+    let equipped = registry.equipped_gems(save.characters[0].class_data(18), &save.inventory);
+    assert_eq!(Some(30), equipped[3].map(|g| g.category_id));
+    assert_eq!(Some(Some(2)), equipped[3].map(|g| g.level));
+}
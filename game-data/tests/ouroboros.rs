@@ -0,0 +1,230 @@
+use game_data::{
+    manual::Flag,
+    ouroboros::{
+        InterlinkFlags, InterlinkState, OuroNodeKind, OuroTreeError, OuroTreeNode, Ouroboros,
+    },
+};
+use recordkeeper::{character::OUROBOROS_TREE_MAX, SaveFile};
+
+mod common;
+
+const SRC: &[u8] = include_bytes!("../../lib/tests/res/save-ch5-v10.sav");
+
+fn ouroboros(id: usize) -> Ouroboros {
+    Ouroboros {
+        id,
+        name_id: 1,
+        share_slot_flag: common::UNUSED_FLAG_BASE + id - 1,
+        tree_nodes: Box::default(),
+    }
+}
+
+/// Skill tree where each node costs its ID in SP, and requires the nodes in
+/// `prerequisites[id - 1]`.
+fn tree(prerequisites: &[&[usize]]) -> Ouroboros {
+    Ouroboros {
+        id: 1,
+        name_id: 1,
+        share_slot_flag: 0,
+        tree_nodes: (1..)
+            .zip(prerequisites)
+            .map(|(id, &pre)| OuroTreeNode {
+                kind: OuroNodeKind::UnlockArt(id),
+                sp_cost: id as u32,
+                prerequisites: pre.into(),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+pub fn interlink_flags() {
+    let flags = common::manual_flags();
+    let flag = |index| Flag { bits: 1, index };
+
+    assert_eq!(
+        InterlinkFlags {
+            enable: flags.ouro_enable_noah,
+            share_slot: flag(common::UNUSED_FLAG_BASE),
+        },
+        ouroboros(1).interlink_flags(&flags)
+    );
+    // Mio's flag is `ouro_enable`, the others follow
+    assert_eq!(
+        flags.ouro_enable,
+        ouroboros(2).interlink_flags(&flags).enable
+    );
+    assert_eq!(
+        InterlinkFlags {
+            enable: flag(flags.ouro_enable.index + 4),
+            share_slot: flag(common::UNUSED_FLAG_BASE + 5),
+        },
+        ouroboros(6).interlink_flags(&flags)
+    );
+}
+
+#[test]
+pub fn interlink_state() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let flags = common::manual_flags();
+    let interlink = |id| ouroboros(id).interlink_flags(&flags);
+
+    // Chapter 5: all pairs can Interlink
+    for id in 1..=6 {
+        assert_eq!(
+            InterlinkState {
+                enabled: true,
+                shared_slot: false
+            },
+            interlink(id).state(save)
+        );
+    }
+
+    let mio = interlink(2);
+    mio.set_enabled(save, false);
+    assert!(!mio.state(save).enabled);
+    // Other pairs are not affected
+    assert!(interlink(1).state(save).enabled);
+    assert!(interlink(3).state(save).enabled);
+
+    // The flags are independent
+    mio.set_shared_slot(save, true);
+    assert_eq!(
+        InterlinkState {
+            enabled: false,
+            shared_slot: true
+        },
+        mio.state(save)
+    );
+    assert!(!interlink(3).state(save).shared_slot);
+
+    mio.set_enabled(save, true);
+    mio.set_shared_slot(save, false);
+    assert_eq!(
+        InterlinkState {
+            enabled: true,
+            shared_slot: false
+        },
+        mio.state(save)
+    );
+}
+
+#[test]
+pub fn unlock_nodes() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let ouroboros = &mut save.save_mut().ouroboros[0];
+    (0..OUROBOROS_TREE_MAX).for_each(|i| ouroboros.skill_tree.set(i, false));
+    let tree = tree(&[&[], &[1], &[2], &[1]]);
+
+    // Unlocking a node also unlocks its prerequisites
+    ouroboros.sp = 10;
+    assert_eq!(Ok(vec![1, 2, 3]), tree.nodes_to_unlock(ouroboros, 3));
+    assert_eq!(Ok(6), tree.unlock_node(ouroboros, 3));
+    assert_eq!(4, ouroboros.sp);
+    assert_eq!(
+        vec![0, 1, 2],
+        ouroboros.skill_tree.unlocked().collect::<Vec<_>>()
+    );
+    // Already unlocked nodes are free
+    assert_eq!(Ok(4), tree.unlock_node(ouroboros, 4));
+    assert_eq!(0, ouroboros.sp);
+    assert_eq!(Ok(0), tree.unlock_node(ouroboros, 2));
+    assert_eq!(
+        Err(OuroTreeError::UnknownNode(5)),
+        tree.unlock_node(ouroboros, 5)
+    );
+
+    // Not enough SP: nothing changes
+    (0..4).for_each(|i| ouroboros.skill_tree.set(i, false));
+    ouroboros.sp = 5;
+    assert_eq!(
+        Err(OuroTreeError::NotEnoughSp {
+            needed: 6,
+            available: 5
+        }),
+        tree.unlock_node(ouroboros, 3)
+    );
+    assert_eq!(5, ouroboros.sp);
+    assert_eq!(0, ouroboros.skill_tree.unlocked().count());
+
+    ouroboros.sp = 9;
+    assert_eq!(
+        Err(OuroTreeError::NotEnoughSp {
+            needed: 10,
+            available: 9
+        }),
+        tree.unlock_all(ouroboros)
+    );
+    assert_eq!(0, ouroboros.skill_tree.unlocked().count());
+    ouroboros.sp = 12;
+    assert_eq!(Ok(10), tree.unlock_all(ouroboros));
+    assert_eq!(2, ouroboros.sp);
+    assert_eq!(4, ouroboros.skill_tree.unlocked().count());
+}
+
+#[test]
+pub fn refund_nodes() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let ouroboros = &mut save.save_mut().ouroboros[0];
+    (0..OUROBOROS_TREE_MAX).for_each(|i| ouroboros.skill_tree.set(i, false));
+    let tree = tree(&[&[], &[1], &[2], &[1]]);
+    ouroboros.sp = 10;
+    tree.unlock_all(ouroboros).unwrap();
+
+    // Refunding a node also refunds the nodes that depend on it
+    assert_eq!(Ok(5), tree.refund_node(ouroboros, 2));
+    assert_eq!(5, ouroboros.sp);
+    assert_eq!(
+        vec![0, 3],
+        ouroboros.skill_tree.unlocked().collect::<Vec<_>>()
+    );
+    assert_eq!(Ok(0), tree.refund_node(ouroboros, 3));
+    assert_eq!(Ok(5), tree.refund_node(ouroboros, 1));
+    assert_eq!(10, ouroboros.sp);
+    assert_eq!(0, ouroboros.skill_tree.unlocked().count());
+    assert_eq!(
+        Err(OuroTreeError::UnknownNode(5)),
+        tree.refund_node(ouroboros, 5)
+    );
+}
+
+#[test]
+pub fn check_tree() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save();
+    // Two branches: odd and even nodes, both starting from node 1
+    let prerequisites = (1..=40)
+        .map(|id: usize| vec![id.saturating_sub(2).max(1)])
+        .collect::<Vec<_>>();
+    let mut prerequisites = prerequisites.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    prerequisites[0] = &[];
+    let tree = tree(&prerequisites);
+
+    // Noah has nodes 1-25, 27 and 29
+    assert!(tree.check_tree(&save.ouroboros[0]).is_empty());
+    // Mio has nodes 1-19, 21, 22, 24-26, 29, 31 and 34
+    let missing = |node, prerequisite| OuroTreeError::MissingPrerequisite { node, prerequisite };
+    assert_eq!(
+        vec![
+            missing(22, 20),
+            missing(25, 23),
+            missing(29, 27),
+            missing(34, 32)
+        ],
+        tree.check_tree(&save.ouroboros[1])
+    );
+
+    let short = Ouroboros {
+        tree_nodes: tree.tree_nodes[..24].into(),
+        ..tree
+    };
+    assert_eq!(
+        vec![
+            OuroTreeError::UnknownNode(25),
+            OuroTreeError::UnknownNode(27),
+            OuroTreeError::UnknownNode(29)
+        ],
+        short.check_tree(&save.ouroboros[0])
+    );
+}
@@ -0,0 +1,101 @@
+use game_data::{
+    scenario::{ScenarioRange, ScenarioRanges},
+    system::{sync_clear_flags, ClearFlagSync},
+};
+use recordkeeper::{flags::FlagType, SaveFile, SystemFile, SystemFlag};
+
+mod common;
+
+const SRC: &[u8] = include_bytes!("../../lib/tests/res/save-ch5-v10.sav");
+const SYSTEM: &[u8] = include_bytes!("../../lib/tests/res/system.sav");
+
+const FINAL_SCENARIO: u16 = 10000;
+
+fn scenarios() -> ScenarioRanges {
+    let ranges = |end| {
+        [
+            ScenarioRange {
+                chapter: 1,
+                range: 1..=999,
+            },
+            ScenarioRange {
+                chapter: 2,
+                range: 1000..=end,
+            },
+        ]
+    };
+    ScenarioRanges::new(ranges(FINAL_SCENARIO), ranges(FINAL_SCENARIO))
+}
+
+fn files() -> (SaveFile, SystemFile) {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let mut system = SystemFile::from_bytes(SYSTEM).unwrap();
+    for flag in [
+        SystemFlag::MainGameClear,
+        SystemFlag::MainGameClearNgp,
+        SystemFlag::Dlc4Clear,
+        SystemFlag::Dlc4ClearNgp,
+    ] {
+        system.save_mut().set_flag(flag, false);
+    }
+    (save, system)
+}
+
+#[test]
+pub fn sync_not_cleared() {
+    let flags = common::manual_flags();
+    let (mut save, mut system) = files();
+
+    // Chapter 5, not cleared
+    let sync = sync_clear_flags(&flags, &scenarios(), &mut save, &mut system);
+    assert_eq!(ClearFlagSync::default(), sync);
+    assert!(!system.save().is_flag_set(SystemFlag::MainGameClear));
+}
+
+#[test]
+pub fn sync_ngp_clear() {
+    let flags = common::manual_flags();
+    let (mut save, mut system) = files();
+    let set = |save: &mut SaveFile, flag: game_data::manual::Flag, value| {
+        save.save_mut()
+            .flags
+            .set(FlagType::from_bits(flag.bits), flag.index, value)
+    };
+
+    // NG+ cycle that carried over the clear flag, but hasn't completed the story yet
+    set(&mut save, flags.game_clear, 1);
+    set(&mut save, flags.new_game_plus, 1);
+    let sync = sync_clear_flags(&flags, &scenarios(), &mut save, &mut system);
+    assert_eq!(vec![SystemFlag::MainGameClear], sync.system_flags);
+    assert!(!system.save().is_flag_set(SystemFlag::MainGameClearNgp));
+
+    // Story completed in NG+
+    set(&mut save, flags.scenario, FINAL_SCENARIO.into());
+    let sync = sync_clear_flags(&flags, &scenarios(), &mut save, &mut system);
+    assert_eq!(vec![SystemFlag::MainGameClearNgp], sync.system_flags);
+    assert!(!sync.save_fr_complete);
+    assert!(!system.save().is_flag_set(SystemFlag::Dlc4ClearNgp));
+
+    // Nothing left to sync
+    let sync = sync_clear_flags(&flags, &scenarios(), &mut save, &mut system);
+    assert_eq!(ClearFlagSync::default(), sync);
+}
+
+#[test]
+pub fn sync_fr_complete() {
+    let flags = common::manual_flags();
+    let (mut save, mut system) = files();
+    let fr_complete = flags.fr_complete;
+    let get = |save: &SaveFile| {
+        save.save()
+            .flags
+            .get(FlagType::from_bits(fr_complete.bits), fr_complete.index)
+    };
+
+    system.save_mut().set_flag(SystemFlag::Dlc4Clear, true);
+    assert_eq!(Some(0), get(&save));
+    let sync = sync_clear_flags(&flags, &scenarios(), &mut save, &mut system);
+    assert!(sync.save_fr_complete);
+    assert!(sync.system_flags.is_empty());
+    assert_eq!(Some(1), get(&save));
+}
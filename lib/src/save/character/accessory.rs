@@ -0,0 +1,272 @@
+//! Validation for accessory references into the inventory.
+//!
+//! Equipped accessories (and Future Redeemed battle manuals) point to a
+//! slot in the inventory. Editing the inventory can leave those references
+//! pointing to empty slots or to different items.
+
+use crate::{
+    dlc::CRAFTED_ITEM_ID,
+    item::{DlcManualSlot, ItemSlot, ItemType},
+    SaveData,
+};
+
+use super::{
+    class::ClassAccessory,
+    slot::{EmptySlotMut, SlotMut},
+    CHARACTER_CLASS_MAX,
+};
+
+/// Where an equipped item reference is stored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessoryLocation {
+    /// Class equipment. Character and class IDs start at 1.
+    Class {
+        char_id: u16,
+        class_id: u16,
+        slot: usize,
+    },
+    /// Class equipment saved in a party formation.
+    Formation {
+        formation: usize,
+        char_id: u16,
+        slot: usize,
+    },
+    /// Future Redeemed battle manual slot.
+    BattleManual { char_id: u16, class_id: u16 },
+}
+
+/// An equipped item that no longer matches its inventory slot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DanglingAccessory {
+    pub location: AccessoryLocation,
+    /// The item ID stored in the equipment slot
+    pub item_id: u16,
+    /// The inventory slot the equipment slot points to
+    pub slot_index: u16,
+}
+
+/// Change made by [`SaveData::repair_accessories`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessoryFix {
+    /// The reference now points to another slot with the same item.
+    Relinked {
+        location: AccessoryLocation,
+        slot_index: u16,
+    },
+    /// No other slot holds the item, or the item is a crafted accessory,
+    /// so the equipment slot was emptied.
+    Cleared { location: AccessoryLocation },
+}
+
+impl SaveData {
+    /// Finds equipped accessories whose inventory slot is empty or holds
+    /// a different item.
+    ///
+    /// Characters' classes, saved party formations and Future Redeemed battle
+    /// manuals are checked. Accessories equipped by heroes don't refer to the
+    /// inventory, and are skipped.
+    pub fn dangling_accessories(&self) -> Vec<DanglingAccessory> {
+        self.equipped_items()
+            .into_iter()
+            .filter(|&(_, item)| !self.is_linked(item))
+            .map(|(location, item)| DanglingAccessory {
+                location,
+                item_id: item.item_id,
+                slot_index: item.slot_index,
+            })
+            .collect()
+    }
+
+    /// Fixes all accessories reported by [`SaveData::dangling_accessories`].
+    ///
+    /// Each reference is moved to the first inventory slot that holds the
+    /// same item. If there is none, the equipment slot is emptied.
+    /// Crafted accessories (DLC3) are always emptied, as each of them has
+    /// unique stats and can't be replaced by another one.
+    pub fn repair_accessories(&mut self) -> Vec<AccessoryFix> {
+        self.dangling_accessories()
+            .into_iter()
+            .map(|dangling| {
+                let location = dangling.location;
+                let replacement = self
+                    .equipment_slot_mut(location)
+                    .get()
+                    .filter(|item| item.item_id != CRAFTED_ITEM_ID)
+                    .and_then(|item| self.replacement(item));
+                let mut slot = self.equipment_slot_mut(location);
+                match replacement {
+                    Some(inventory_slot) => {
+                        slot.set_from_inventory(&inventory_slot);
+                        AccessoryFix::Relinked {
+                            location,
+                            slot_index: inventory_slot.index(),
+                        }
+                    }
+                    None => {
+                        slot.set_empty();
+                        AccessoryFix::Cleared { location }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Returns all equipment slots that hold an item, see
+    /// [`SaveData::dangling_accessories`] for the list of locations.
+    pub(crate) fn equipped_items(&self) -> Vec<(AccessoryLocation, InventoryRef)> {
+        let mut items = Vec::new();
+
+        for (char_id, character) in (1..).zip(self.characters.iter()) {
+            for class_id in (1..).take(CHARACTER_CLASS_MAX) {
+                let class = character.class_data(usize::from(class_id));
+                for (slot, accessory) in class.accessories().enumerate() {
+                    if let Some(accessory) = accessory.get() {
+                        let location = AccessoryLocation::Class {
+                            char_id,
+                            class_id,
+                            slot,
+                        };
+                        items.push((location, accessory.into()));
+                    }
+                }
+            }
+        }
+
+        for (formation, party) in self.party_formations.iter().enumerate() {
+            if !party.is_valid() {
+                continue;
+            }
+            for character in party.characters.iter().filter(|c| c.character_id != 0) {
+                for (slot, accessory) in character.class.accessories().enumerate() {
+                    if let Some(accessory) = accessory.get() {
+                        let location = AccessoryLocation::Formation {
+                            formation,
+                            char_id: character.character_id,
+                            slot,
+                        };
+                        items.push((location, accessory.into()));
+                    }
+                }
+            }
+        }
+
+        for char_id in (1..).take(self.characters.len()) {
+            let manuals = self.dlc4.battle_manuals(usize::from(char_id));
+            for (class_id, manual) in (1..).zip(manuals) {
+                if let Some(manual) = manual.get() {
+                    let location = AccessoryLocation::BattleManual { char_id, class_id };
+                    items.push((location, manual.into()));
+                }
+            }
+        }
+
+        items
+    }
+
+    pub(crate) fn is_linked(&self, item: InventoryRef) -> bool {
+        let Some(item_type) = item.item_type else {
+            return true;
+        };
+        self.inventory
+            .slots(item_type)
+            .get(usize::from(item.slot_index))
+            .is_some_and(|s| s.is_valid() && s.item_id() == item.item_id)
+    }
+
+    fn replacement(&self, item: InventoryRef) -> Option<ItemSlot> {
+        let item_type = item.item_type?;
+        self.inventory
+            .slots(item_type)
+            .iter()
+            .find(|s| s.is_valid() && s.item_id() == item.item_id)
+            .copied()
+    }
+
+    pub(crate) fn equipment_slot_mut(
+        &mut self,
+        location: AccessoryLocation,
+    ) -> EquipmentSlotMut<'_> {
+        match location {
+            AccessoryLocation::Class {
+                char_id,
+                class_id,
+                slot,
+            } => EquipmentSlotMut::Accessory(
+                self.characters[usize::from(char_id - 1)]
+                    .class_data_mut(usize::from(class_id))
+                    .accessory_slot_mut(slot),
+            ),
+            AccessoryLocation::Formation {
+                formation,
+                char_id,
+                slot,
+            } => EquipmentSlotMut::Accessory(
+                self.party_formations[formation]
+                    .character_mut(char_id)
+                    .class
+                    .accessory_slot_mut(slot),
+            ),
+            AccessoryLocation::BattleManual { char_id, class_id } => EquipmentSlotMut::Manual(
+                self.dlc4
+                    .battle_manual_mut(usize::from(char_id), usize::from(class_id)),
+            ),
+        }
+    }
+}
+
+/// Inventory reference held by an equipment slot.
+#[derive(Clone, Copy)]
+pub(crate) struct InventoryRef {
+    /// [`None`] if the slot doesn't refer to the inventory
+    pub(crate) item_type: Option<ItemType>,
+    pub(crate) item_id: u16,
+    pub(crate) slot_index: u16,
+}
+
+pub(crate) enum EquipmentSlotMut<'a> {
+    Accessory(SlotMut<'a, ClassAccessory>),
+    Manual(SlotMut<'a, DlcManualSlot>),
+}
+
+impl<'a> EquipmentSlotMut<'a> {
+    fn get(&self) -> Option<InventoryRef> {
+        match self {
+            Self::Accessory(slot) => slot.get().map(Into::into),
+            Self::Manual(slot) => slot.get().map(Into::into),
+        }
+    }
+
+    pub(crate) fn set_from_inventory(&mut self, inventory_slot: &ItemSlot) {
+        match self {
+            Self::Accessory(slot) => slot.set_from_inventory(inventory_slot),
+            Self::Manual(slot) => slot.set_from_inventory(inventory_slot),
+        }
+    }
+
+    fn set_empty(&mut self) {
+        match self {
+            Self::Accessory(slot) => slot.set_empty(),
+            Self::Manual(slot) => slot.set_empty(),
+        }
+    }
+}
+
+impl From<ClassAccessory> for InventoryRef {
+    fn from(accessory: ClassAccessory) -> Self {
+        Self {
+            item_type: accessory.inventory_type(),
+            item_id: accessory.bdat_id(),
+            slot_index: accessory.slot_index(),
+        }
+    }
+}
+
+impl From<DlcManualSlot> for InventoryRef {
+    fn from(manual: DlcManualSlot) -> Self {
+        Self {
+            item_type: manual.inventory_type(),
+            item_id: manual.item_id(),
+            slot_index: manual.inventory_slot_index(),
+        }
+    }
+}
@@ -38,6 +38,27 @@ pub struct ClassAccessory {
 }
 
 impl CharacterClass {
+    /// Returns the class rank. Rank 0 means the class is not unlocked.
+    pub fn rank(&self) -> u8 {
+        self.level
+    }
+
+    /// Sets the class rank, and updates CP to the minimum amount
+    /// required for that rank.
+    ///
+    /// `rank_cp` holds the total CP required to reach each rank, starting from
+    /// rank 1 (index 0). Setting the rank to 0 also resets CP.
+    ///
+    /// ## Panics
+    /// This function panics if the rank is greater than `rank_cp.len()`.
+    pub fn set_rank(&mut self, rank: u8, rank_cp: &[u32]) {
+        self.cp = match rank.checked_sub(1) {
+            Some(index) => rank_cp[usize::from(index)],
+            None => 0,
+        };
+        self.level = rank;
+    }
+
     pub fn art_slot(&self, index: usize) -> Slot<u16> {
         Slot(self.arts[index])
     }
@@ -108,13 +129,43 @@ impl ClassAccessory {
         self.bdat_id
     }
 
-    pub fn item_type(&self) -> ItemType {
-        todo!()
+    /// Returns the type of the equipped item, or [`None`] if the slot
+    /// is empty or the item type is invalid.
+    ///
+    /// For accessories equipped by heroes, the upper byte of the raw
+    /// value is set, and it is ignored here.
+    pub fn item_type(&self) -> Option<ItemType> {
+        ItemType::try_from(u32::from(self.item_type & 0xff)).ok()
+    }
+
+    /// Returns the raw item type, including the upper byte set for
+    /// accessories equipped by heroes.
+    pub fn raw_item_type(&self) -> u16 {
+        self.item_type
     }
 
     pub fn slot_index(&self) -> u16 {
         self.slot_index
     }
+
+    /// Returns whether the accessory refers to a slot in the accessory inventory.
+    ///
+    /// This is not the case for accessories equipped by heroes, whose slot index
+    /// points to a different table.
+    pub fn is_from_inventory(&self) -> bool {
+        self.item_type == ItemType::Accessory as u16
+    }
+
+    /// Returns the inventory type the slot index refers to, if the item
+    /// is an inventory reference.
+    pub(crate) fn inventory_type(&self) -> Option<ItemType> {
+        if self.item_type > 0xff {
+            return None;
+        }
+        ItemType::try_from(u32::from(self.item_type))
+            .ok()
+            .filter(|&t| t != ItemType::Collectopedia)
+    }
 }
 
 impl<'a> SlotMut<'a, ClassAccessory> {
@@ -132,6 +183,18 @@ impl<'a> SlotMut<'a, ClassAccessory> {
         out.bdat_id = inventory_slot.item_id();
         out.item_type = inventory_slot.item_type() as u16;
     }
+
+    /// Sets the accessory slot from raw values, e.g. to restore an accessory
+    /// equipped by a hero, which doesn't refer to the inventory.
+    ///
+    /// See [`ClassAccessory::raw_item_type`].
+    pub fn set_raw(&mut self, bdat_id: u16, slot_index: u16, item_type: u16) {
+        *self.0 = ClassAccessory {
+            bdat_id,
+            slot_index,
+            item_type,
+        };
+    }
 }
 
 impl EmptySlot for Slot<u8> {
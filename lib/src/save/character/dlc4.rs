@@ -0,0 +1,109 @@
+//! Equipment slot expansions for Future Redeemed characters.
+//!
+//! In Future Redeemed, master art, gem and accessory slots are unlocked
+//! separately for each character, and their status is stored in
+//! [`CharacterFlag`]s.
+
+use std::ops::Range;
+
+use super::{
+    class::{CharacterClass, CHARACTER_CLASS_GEM_MAX},
+    slot::EmptySlot,
+    Character, CharacterFlag,
+};
+
+/// Indices of the master art slots in [`CharacterClass`] arts, for
+/// Future Redeemed characters. Slot 0 holds the talent art, and the
+/// last three slots hold class arts.
+pub const DLC4_MASTER_ART_SLOTS: Range<usize> = 1..4;
+
+const MASTER_ART_FLAGS: [CharacterFlag; 3] = [
+    CharacterFlag::Dlc4MasterArt1,
+    CharacterFlag::Dlc4MasterArt2,
+    CharacterFlag::Dlc4MasterArt3,
+];
+const GEM_FLAGS: [CharacterFlag; 2] = [CharacterFlag::Dlc4Gem2, CharacterFlag::Dlc4Gem3];
+const ACCESSORY_FLAGS: [CharacterFlag; 2] =
+    [CharacterFlag::Dlc4Accessory2, CharacterFlag::Dlc4Accessory3];
+
+/// Number of equipment slots a Future Redeemed character can use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dlc4Slots {
+    pub master_arts: usize,
+    pub gems: usize,
+    pub accessories: usize,
+}
+
+/// An equipment slot that is in use, but not unlocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockedSlot {
+    /// Index into the class's arts
+    Art(usize),
+    Gem(usize),
+    Accessory(usize),
+}
+
+impl Dlc4Slots {
+    /// Slot counts when all expansions are unlocked.
+    pub const MAX: Self = Self {
+        master_arts: MASTER_ART_FLAGS.len(),
+        gems: 1 + GEM_FLAGS.len(),
+        accessories: 1 + ACCESSORY_FLAGS.len(),
+    };
+
+    /// Returns the equipped slots in the class that are not
+    /// unlocked.
+    pub fn locked_slots(&self, class: &CharacterClass) -> Vec<LockedSlot> {
+        let master_arts = DLC4_MASTER_ART_SLOTS
+            .skip(self.master_arts)
+            .filter(|&i| !class.art_slot(i).is_empty())
+            .map(LockedSlot::Art);
+        let gems = (self.gems..CHARACTER_CLASS_GEM_MAX)
+            .filter(|&i| !class.gem_slot(i).is_empty())
+            .map(LockedSlot::Gem);
+        let accessories = class
+            .accessories()
+            .enumerate()
+            .skip(self.accessories)
+            .filter(|(_, slot)| !slot.is_empty())
+            .map(|(i, _)| LockedSlot::Accessory(i));
+        master_arts.chain(gems).chain(accessories).collect()
+    }
+}
+
+impl Character {
+    /// Returns the number of equipment slots unlocked for the character
+    /// in Future Redeemed.
+    ///
+    /// The first gem and accessory slots are always available.
+    pub fn dlc4_slots(&self) -> Dlc4Slots {
+        let count =
+            |flags: &[CharacterFlag]| flags.iter().filter(|&&f| self.is_flag_set(f)).count();
+        Dlc4Slots {
+            master_arts: count(&MASTER_ART_FLAGS),
+            gems: 1 + count(&GEM_FLAGS),
+            accessories: 1 + count(&ACCESSORY_FLAGS),
+        }
+    }
+
+    /// Unlocks all Future Redeemed master art, gem and accessory slots
+    /// for the character.
+    pub fn unlock_dlc4_slots(&mut self) {
+        for flag in MASTER_ART_FLAGS
+            .into_iter()
+            .chain(GEM_FLAGS)
+            .chain(ACCESSORY_FLAGS)
+        {
+            self.set_flag(flag, true);
+        }
+    }
+
+    /// Returns the equipped slots in the given class that the character
+    /// has not unlocked in Future Redeemed.
+    ///
+    /// ## Panics
+    /// Panics if the class ID is out of bounds.
+    pub fn dlc4_locked_slots(&self, class_id: usize) -> Vec<LockedSlot> {
+        self.dlc4_slots().locked_slots(self.class_data(class_id))
+    }
+}
@@ -1,17 +1,22 @@
 use recordkeeper_macros::SaveBin;
 
-use crate::{util::FixVec, SaveData};
+use crate::{
+    item::{Inventory, ItemType},
+    util::FixVec,
+    SaveData,
+};
 
 use super::{
     class::CharacterClass,
-    slot::{Slot, SlotMut},
-    Character, Ouroboros, CHARACTER_MAX, OUROBOROS_ART_MAX, OUROBOROS_MAX, OUROBOROS_SKILL_MAX,
-    PARTY_MAX,
+    party::PartyError,
+    slot::{EmptySlotMut, Slot, SlotMut},
+    Character, Ouroboros, CHARACTER_CLASS_MAX, CHARACTER_MAX, OUROBOROS_ART_MAX, OUROBOROS_MAX,
+    OUROBOROS_SKILL_MAX, PARTY_MAX,
 };
 
 pub const PARTY_FORMATION_MAX: usize = 15;
 
-#[derive(SaveBin, Debug)]
+#[derive(SaveBin, Debug, Clone)]
 #[size(9360)]
 pub struct PartyFormation {
     pub name: FormationName,
@@ -22,7 +27,7 @@ pub struct PartyFormation {
     pub ouroboros: [OuroborosFormation; OUROBOROS_MAX],
 }
 
-#[derive(SaveBin, Debug, Default)]
+#[derive(SaveBin, Debug, Default, Clone, Copy)]
 pub struct FormationName {
     /// ID for `33F137E8`
     pub name_id: u16,
@@ -32,7 +37,7 @@ pub struct FormationName {
     pub color_id: u16,
 }
 
-#[derive(SaveBin, Debug)]
+#[derive(SaveBin, Debug, Clone, Copy)]
 #[size(144)]
 pub struct CharacterFormation {
     #[loc(0x4)]
@@ -43,13 +48,58 @@ pub struct CharacterFormation {
     pub attachment: u8,
 }
 
-#[derive(SaveBin, Debug)]
+#[derive(SaveBin, Debug, Clone, Copy)]
 pub struct OuroborosFormation {
     pub ouroboros_id: u16,
     pub art_ids: [u16; OUROBOROS_ART_MAX],
     pub linked_skills: [u16; OUROBOROS_SKILL_MAX],
 }
 
+/// Results of [`PartyFormation::apply_to`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormationReport {
+    /// Parts of the formation that could not be applied
+    pub issues: Vec<FormationIssue>,
+}
+
+/// Part of a formation that could not be applied to the save file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormationIssue {
+    /// None of the formation's characters can be added to the party, so
+    /// the formation was not applied.
+    EmptyParty,
+    /// The character ID is out of bounds, so the slot was skipped.
+    UnknownCharacter(u16),
+    /// The character can no longer be added to the party, so they
+    /// were left out.
+    CharacterNotSelectable(u16),
+    /// Adding the character would break party rules (e.g. too many heroes),
+    /// so they were left out.
+    PartyRule { char_id: u16, error: PartyError },
+    /// The class ID is out of bounds, so the selected class was not changed.
+    UnknownClass { char_id: u16, class_id: u16 },
+    /// The character's selected class is not unlocked, so the
+    /// selected class was not changed.
+    ClassLocked { char_id: u16, class_id: u16 },
+    /// The accessory is no longer in the inventory, so the slot was emptied.
+    MissingAccessory {
+        char_id: u16,
+        class_id: u16,
+        slot: usize,
+        item_id: u16,
+    },
+    /// There are no gems of the category (`ITM_Gem.Category`) in the
+    /// inventory, so the slot was emptied.
+    MissingGem {
+        char_id: u16,
+        class_id: u16,
+        slot: usize,
+        category_id: u32,
+    },
+    /// The Ouroboros ID is out of bounds, so the slot was skipped.
+    UnknownOuroboros(u16),
+}
+
 impl PartyFormation {
     /// Creates a new party formation from the current state of the save file.
     pub fn from_save(save: &SaveData, name: FormationName) -> Self {
@@ -142,10 +192,98 @@ impl PartyFormation {
         slot
     }
 
+    /// Restores the formation to the save file. This is the inverse of
+    /// [`PartyFormation::from_save`].
+    ///
+    /// The party, each character's selected class and equipment, costumes,
+    /// attachments and Ouroboros art/skill choices are copied over. Class progress
+    /// (CP, rank, unlock points) is kept from the save file.
+    ///
+    /// The party is rebuilt through [`Party`], so party rules are enforced. If
+    /// no characters could be added, nothing is applied.
+    ///
+    /// Gem slots are only kept if `has_gem` returns `true` for the inventory and
+    /// the gem category (`ITM_Gem.Category`), as gem items depend on game data.
+    ///
+    /// Parts that can't be applied are skipped and listed in the returned report.
+    ///
+    /// This does nothing if the formation slot is not [valid].
+    ///
+    /// [valid]: PartyFormation::is_valid
+    /// [`Party`]: super::party::Party
+    pub fn apply_to(
+        &self,
+        save: &mut SaveData,
+        has_gem: impl Fn(&Inventory, u32) -> bool,
+    ) -> FormationReport {
+        let mut report = FormationReport::default();
+        if !self.is_valid() {
+            return report;
+        }
+
+        if !self.apply_party(save, &mut report) {
+            report.issues.push(FormationIssue::EmptyParty);
+            return report;
+        }
+        for slot in self.characters.iter().filter(|c| c.character_id != 0) {
+            slot.apply_to(save, &has_gem, &mut report);
+        }
+        for slot in self.ouroboros.iter().filter(|o| o.ouroboros_id != 0) {
+            let Some(ouroboros) = save.ouroboros.get_mut(usize::from(slot.ouroboros_id - 1)) else {
+                report
+                    .issues
+                    .push(FormationIssue::UnknownOuroboros(slot.ouroboros_id));
+                continue;
+            };
+            ouroboros.art_ids = slot.art_ids;
+            ouroboros.linked_skills = slot.linked_skills;
+        }
+
+        report
+    }
+
     /// Clears the saved formation.
     pub fn clear(&mut self) {
         self.party.clear();
     }
+
+    /// Replaces the active party with the formation's party. The controlled
+    /// character is kept if they are still in the party.
+    ///
+    /// Returns `false` (and restores the previous party) if the new party
+    /// would be empty.
+    fn apply_party(&self, save: &mut SaveData, report: &mut FormationReport) -> bool {
+        let (prev_party, prev_controlled) =
+            (save.party_characters.clone(), save.controlled_character_idx);
+        let controlled = save.party_mut().controlled();
+
+        save.party_characters.clear();
+        let mut party = save.party_mut();
+        let mut issues = Vec::new();
+        for &char_id in self.party.iter() {
+            match party.add(char_id) {
+                Ok(()) => {}
+                Err(PartyError::NotSelectable(_)) => {
+                    issues.push(FormationIssue::CharacterNotSelectable(char_id))
+                }
+                Err(error) => issues.push(FormationIssue::PartyRule { char_id, error }),
+            }
+        }
+
+        if party.is_empty() {
+            save.party_characters = prev_party;
+            save.controlled_character_idx = prev_controlled;
+            return false;
+        }
+        let leader = party.members().next().unwrap();
+        match controlled {
+            Some(id) if party.set_controlled(id).is_ok() => {}
+            _ => party.set_controlled(leader).unwrap(),
+        }
+        debug_assert_eq!(Ok(()), party.validate());
+        report.issues.extend(issues);
+        true
+    }
 }
 
 impl CharacterFormation {
@@ -165,6 +303,82 @@ impl CharacterFormation {
         }
     }
 
+    fn apply_to(
+        &self,
+        save: &mut SaveData,
+        has_gem: impl Fn(&Inventory, u32) -> bool,
+        report: &mut FormationReport,
+    ) {
+        let char_id = self.character_id;
+        let class_id = self.current_class;
+        let Some(character) = save.characters.get_mut(usize::from(char_id - 1)) else {
+            report
+                .issues
+                .push(FormationIssue::UnknownCharacter(char_id));
+            return;
+        };
+        character.costume_id = self.costume_id;
+        character.attachment = self.attachment;
+        if class_id == 0 {
+            return;
+        }
+        if usize::from(class_id) > CHARACTER_CLASS_MAX {
+            report
+                .issues
+                .push(FormationIssue::UnknownClass { char_id, class_id });
+            return;
+        }
+
+        let class = character.class_data_mut(usize::from(class_id));
+        if class.level == 0 {
+            report
+                .issues
+                .push(FormationIssue::ClassLocked { char_id, class_id });
+            return;
+        }
+        let (cp, unlock_points, level) = (class.cp, class.unlock_points, class.level);
+        *class = self.class;
+        class.cp = cp;
+        class.unlock_points = unlock_points;
+        class.level = level;
+        character.selected_class = class_id.try_into().unwrap();
+
+        let class = character.class_data_mut(usize::from(class_id));
+        for (i, mut slot) in class.gems_mut().enumerate() {
+            let Some(category_id) = slot.get().map(|raw| u32::from(raw) + 1) else {
+                continue;
+            };
+            if !has_gem(&save.inventory, category_id) {
+                report.issues.push(FormationIssue::MissingGem {
+                    char_id,
+                    class_id,
+                    slot: i,
+                    category_id,
+                });
+                slot.set(None);
+            }
+        }
+
+        let accessories = save.inventory.slots(ItemType::Accessory);
+        for (i, mut slot) in class.accessories_mut().enumerate() {
+            let Some(accessory) = slot.get().filter(|a| a.is_from_inventory()) else {
+                continue;
+            };
+            let owned = accessories
+                .get(usize::from(accessory.slot_index()))
+                .is_some_and(|s| s.is_valid() && s.item_id() == accessory.bdat_id());
+            if !owned {
+                report.issues.push(FormationIssue::MissingAccessory {
+                    char_id,
+                    class_id,
+                    slot: i,
+                    item_id: accessory.bdat_id(),
+                });
+                slot.set_empty();
+            }
+        }
+    }
+
     /// Copies class data from the save file format of a character class.
     pub fn copy_class_from_save(&mut self, class: &CharacterClass) {
         self.class = *class;
@@ -10,13 +10,18 @@ pub const PARTY_GUEST_MAX: usize = 8;
 pub const CHARACTER_MAX: usize = 64;
 pub const OUROBOROS_MAX: usize = 6;
 
-const CHARACTER_CLASS_MAX: usize = 64;
+pub(crate) const CHARACTER_CLASS_MAX: usize = 64;
 
 pub const OUROBOROS_ART_MAX: usize = 5;
 pub const OUROBOROS_SKILL_MAX: usize = 2;
+/// Number of nodes that can be stored in an ouroboros skill tree
+pub const OUROBOROS_TREE_MAX: usize = 64;
 
+pub mod accessory;
 pub mod class;
+pub mod dlc4;
 pub mod formation;
+pub mod party;
 pub mod slot;
 
 #[derive(SaveBin, Debug, Clone, Copy)]
@@ -85,6 +90,25 @@ pub struct OuroborosTree {
     raw: BitFlags<1, 2>,
 }
 
+impl CharacterSets {
+    /// Updates all sets for the given character.
+    ///
+    /// ## Panics
+    /// Panics if the character ID is out of bounds.
+    pub fn set_status(
+        &mut self,
+        char_id: usize,
+        selectable: bool,
+        permanent: bool,
+        temporary: bool,
+    ) {
+        let index = char_id.checked_sub(1).expect("character ID must be >= 1");
+        self.selectable_characters.set(index, selectable.into());
+        self.permanent_characters.set(index, permanent.into());
+        self.temporary_characters.set(index, temporary.into());
+    }
+}
+
 impl Character {
     pub fn class_data(&self, class_id: usize) -> &CharacterClass {
         &self.class_inventory[class_id.checked_sub(1).expect("class ID must be >= 1")]
@@ -101,6 +125,54 @@ impl Character {
     pub fn set_flag(&mut self, flag: CharacterFlag, value: bool) {
         self.flags.set(flag as usize, u8::from(value).into())
     }
+
+    /// Updates the character's level, setting EXP to the minimum
+    /// amount required to reach it.
+    ///
+    /// `level_exp` is the total EXP required to reach each level, starting
+    /// from level 1 (index 0).
+    ///
+    /// Bonus EXP is not affected, as it is a separate pool that hasn't been
+    /// applied to the character yet.
+    ///
+    /// ## Panics
+    /// Panics if the level is not in the table's bounds.
+    pub fn set_level(&mut self, level: u32, level_exp: &[u32]) {
+        let exp = level
+            .checked_sub(1)
+            .and_then(|i| level_exp.get(i as usize))
+            .expect("level out of bounds");
+        self.level = level;
+        self.exp = *exp;
+    }
+
+    /// Updates the character's total EXP, and sets their level accordingly.
+    ///
+    /// `level_exp` is the total EXP required to reach each level, starting
+    /// from level 1 (index 0). EXP exceeding the maximum level's requirement
+    /// is kept as-is.
+    pub fn set_exp(&mut self, exp: u32, level_exp: &[u32]) {
+        self.exp = exp;
+        self.level = level_for_exp(exp, level_exp);
+    }
+
+    /// Returns whether the character's level matches their total EXP.
+    ///
+    /// `level_exp` is the total EXP required to reach each level, starting
+    /// from level 1 (index 0).
+    pub fn is_level_consistent(&self, level_exp: &[u32]) -> bool {
+        self.level != 0 && self.level == level_for_exp(self.exp, level_exp)
+    }
+}
+
+/// Returns the highest level that can be reached with the given
+/// amount of EXP.
+fn level_for_exp(exp: u32, level_exp: &[u32]) -> u32 {
+    level_exp
+        .partition_point(|&required| required <= exp)
+        .max(1)
+        .try_into()
+        .unwrap()
 }
 
 impl Ouroboros {
@@ -129,4 +201,9 @@ impl OuroborosTree {
     pub fn set(&mut self, index: usize, val: bool) {
         self.raw.set(index, u8::from(val).into())
     }
+
+    /// Returns the indices of all unlocked nodes.
+    pub fn unlocked(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..OUROBOROS_TREE_MAX).filter(|&i| self.get(i))
+    }
 }
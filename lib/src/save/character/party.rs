@@ -0,0 +1,258 @@
+use thiserror::Error;
+
+use crate::SaveData;
+
+/// Maximum number of characters in the base game party (the six main characters and a hero)
+pub const PARTY_BASE_MAX: usize = 7;
+/// Maximum number of characters in the Future Redeemed party
+pub const PARTY_DLC4_MAX: usize = 6;
+
+/// Checked access to the active party, see [`SaveData::party_mut`].
+///
+/// All operations keep the party free of duplicates and locked characters,
+/// and keep the controlled character index pointing to a party member.
+pub struct Party<'a> {
+    save: &'a mut SaveData,
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum PartyError {
+    #[error("character {0} can't be added to the party")]
+    NotSelectable(u16),
+    #[error("character {0} is already in the party")]
+    Duplicate(u16),
+    #[error("character {0} is not in the party")]
+    NotInParty(u16),
+    #[error("the party is full")]
+    Full,
+    #[error("the party can't have more heroes")]
+    TooManyHeroes,
+    #[error("the party can't be empty")]
+    Empty,
+    #[error("the controlled character is not in the party")]
+    InvalidControlled,
+    #[error("guest {0} is already in the party")]
+    DuplicateGuest(u16),
+    #[error("guest {0} is not in the party")]
+    GuestNotInParty(u16),
+    #[error("the party can't have more guests")]
+    GuestsFull,
+}
+
+impl SaveData {
+    /// Returns a handle to manage the active party.
+    pub fn party_mut(&mut self) -> Party<'_> {
+        Party { save: self }
+    }
+}
+
+impl<'a> Party<'a> {
+    /// Returns the IDs of the characters in the party, in order.
+    pub fn members(&self) -> impl Iterator<Item = u16> + '_ {
+        self.save.party_characters.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.save.party_characters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.save.party_characters.is_empty()
+    }
+
+    /// Returns the maximum party size for the save file.
+    pub fn max_len(&self) -> usize {
+        if self.save.is_dlc4() {
+            PARTY_DLC4_MAX
+        } else {
+            PARTY_BASE_MAX
+        }
+    }
+
+    /// Returns the maximum number of non-main characters that can be
+    /// in the party at the same time.
+    pub fn max_heroes(&self) -> usize {
+        self.max_len() - self.save.main_character_ids().count()
+    }
+
+    /// Returns the ID of the character controlled by the player.
+    pub fn controlled(&self) -> Option<u16> {
+        self.save
+            .party_characters
+            .get(self.controlled_index())
+            .copied()
+    }
+
+    /// Adds a character at the end of the party.
+    ///
+    /// The character must be selectable according to [`CharacterSets`],
+    /// and the party size rules for the save type are enforced.
+    ///
+    /// [`CharacterSets`]: crate::character::CharacterSets
+    pub fn add(&mut self, char_id: u16) -> Result<(), PartyError> {
+        if !self.is_selectable(char_id) {
+            return Err(PartyError::NotSelectable(char_id));
+        }
+        if self.position(char_id).is_some() {
+            return Err(PartyError::Duplicate(char_id));
+        }
+        if self.len() >= self.max_len() {
+            return Err(PartyError::Full);
+        }
+        if !self.is_main(char_id) && self.hero_count() >= self.max_heroes() {
+            return Err(PartyError::TooManyHeroes);
+        }
+        self.save
+            .party_characters
+            .try_push(char_id)
+            .map_err(|_| PartyError::Full)
+    }
+
+    /// Removes a character from the party.
+    ///
+    /// If the removed character was being controlled, control goes to the
+    /// party leader.
+    pub fn remove(&mut self, char_id: u16) -> Result<(), PartyError> {
+        let index = self
+            .position(char_id)
+            .ok_or(PartyError::NotInParty(char_id))?;
+        if self.len() == 1 {
+            return Err(PartyError::Empty);
+        }
+        let mut members: Vec<_> = self.members().collect();
+        members.remove(index);
+        self.set_members(&members);
+
+        let controlled = self.controlled_index();
+        if controlled == index {
+            self.set_controlled_index(0);
+        } else if controlled > index {
+            self.set_controlled_index(controlled - 1);
+        }
+        Ok(())
+    }
+
+    /// Moves the party member at index `from` to index `to`, shifting
+    /// other members. The same character stays controlled.
+    ///
+    /// ## Panics
+    /// Panics if either index is out of bounds.
+    pub fn move_member(&mut self, from: usize, to: usize) {
+        let controlled = self.controlled();
+        let mut members: Vec<_> = self.members().collect();
+        let char_id = members.remove(from);
+        members.insert(to, char_id);
+        self.set_members(&members);
+        if let Some(controlled) = controlled {
+            self.set_controlled(controlled).unwrap();
+        }
+    }
+
+    /// Makes a party member the controlled character.
+    pub fn set_controlled(&mut self, char_id: u16) -> Result<(), PartyError> {
+        let index = self
+            .position(char_id)
+            .ok_or(PartyError::NotInParty(char_id))?;
+        self.set_controlled_index(index);
+        Ok(())
+    }
+
+    /// Returns the IDs (from `FLD_NpcList`) of the guests following the party.
+    pub fn guests(&self) -> impl Iterator<Item = u16> + '_ {
+        self.save.party_guests.iter().copied()
+    }
+
+    /// Adds a guest (ID from `FLD_NpcList`) to the party.
+    pub fn add_guest(&mut self, npc_id: u16) -> Result<(), PartyError> {
+        if self.guests().any(|id| id == npc_id) {
+            return Err(PartyError::DuplicateGuest(npc_id));
+        }
+        self.save
+            .party_guests
+            .try_push(npc_id)
+            .map_err(|_| PartyError::GuestsFull)
+    }
+
+    /// Removes a guest from the party. Other guests keep their order.
+    pub fn remove_guest(&mut self, npc_id: u16) -> Result<(), PartyError> {
+        let index = self
+            .guests()
+            .position(|id| id == npc_id)
+            .ok_or(PartyError::GuestNotInParty(npc_id))?;
+        let mut guests: Vec<_> = self.guests().collect();
+        guests.remove(index);
+        let party_guests = &mut self.save.party_guests;
+        party_guests.clear();
+        for id in guests {
+            party_guests.try_push(id).unwrap();
+        }
+        Ok(())
+    }
+
+    /// Checks whether the party, as currently stored in the save file,
+    /// follows all rules enforced by this API.
+    pub fn validate(&self) -> Result<(), PartyError> {
+        if self.is_empty() {
+            return Err(PartyError::Empty);
+        }
+        if self.len() > self.max_len() {
+            return Err(PartyError::Full);
+        }
+        if self.hero_count() > self.max_heroes() {
+            return Err(PartyError::TooManyHeroes);
+        }
+        for (i, char_id) in self.members().enumerate() {
+            if self.members().take(i).any(|id| id == char_id) {
+                return Err(PartyError::Duplicate(char_id));
+            }
+            if !self.is_selectable(char_id) {
+                return Err(PartyError::NotSelectable(char_id));
+            }
+        }
+        if self.controlled().is_none() {
+            return Err(PartyError::InvalidControlled);
+        }
+        Ok(())
+    }
+
+    /// Only the lowest byte of the stored index is used by the game (see
+    /// [`SaveData::controlled_character_idx`]), the other bytes are left untouched.
+    fn controlled_index(&self) -> usize {
+        usize::from(self.save.controlled_character_idx as u8)
+    }
+
+    fn set_controlled_index(&mut self, index: usize) {
+        let index = u8::try_from(index).expect("party index out of bounds");
+        let idx = &mut self.save.controlled_character_idx;
+        *idx = (*idx & !0xff) | u32::from(index);
+    }
+
+    fn position(&self, char_id: u16) -> Option<usize> {
+        self.members().position(|id| id == char_id)
+    }
+
+    fn is_selectable(&self, char_id: u16) -> bool {
+        usize::from(char_id)
+            .checked_sub(1)
+            .and_then(|i| self.save.character_sets.selectable_characters.get(i))
+            .is_some_and(|v| v != 0)
+    }
+
+    fn is_main(&self, char_id: u16) -> bool {
+        self.save
+            .main_character_ids()
+            .contains(&usize::from(char_id))
+    }
+
+    fn hero_count(&self) -> usize {
+        self.members().filter(|&id| !self.is_main(id)).count()
+    }
+
+    fn set_members(&mut self, members: &[u16]) {
+        let party = &mut self.save.party_characters;
+        party.clear();
+        for &id in members {
+            party.try_push(id).unwrap();
+        }
+    }
+}
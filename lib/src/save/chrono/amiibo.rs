@@ -1,5 +1,6 @@
 use crate::util::FixStr;
 use recordkeeper_macros::SaveBin;
+use thiserror::Error;
 
 /// aka `nn::time::PeriodicBenefitClaimContext`
 #[derive(SaveBin, Debug)]
@@ -22,7 +23,7 @@ pub struct AmiiboTimeData {
 /// `nn::time::ClockSnapshot`
 #[derive(SaveBin, Debug)]
 #[size(208)]
-struct ClockSnapshot {
+pub struct ClockSnapshot {
     system_time_context: TimeContext,
     network_time_context: TimeContext,
 
@@ -56,7 +57,7 @@ struct TimeContext {
 // https://switchbrew.org/wiki/Glue_services#SteadyClockTimePoint
 #[derive(SaveBin, Debug)]
 #[size(24)]
-struct SteadyClockTime {
+pub struct SteadyClockTime {
     /// Seconds since the steady clock epoch
     pub timestamp: u64,
     /// State (seed?/key?), needs to match when comparing
@@ -64,9 +65,9 @@ struct SteadyClockTime {
     state: [u8; 16],
 }
 
-#[derive(SaveBin, Debug)]
+#[derive(SaveBin, Debug, Clone, Copy, PartialEq, Eq)]
 #[size(8)]
-struct CalendarTime {
+pub struct CalendarTime {
     pub year: u16,
     pub month: u8,
     pub day: u8,
@@ -87,3 +88,272 @@ struct CalendarAdditionalInfo {
     #[loc(0x14)]
     utc_offset_seconds: i32,
 }
+
+/// The timestamp is outside the range of years supported by [`CalendarTime`].
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("timestamp {0} is out of the calendar range")]
+pub struct TimeRangeError(pub i64);
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+impl AmiiboTimeData {
+    /// Returns the clock snapshot taken when the game last checked
+    /// whether the amiibo bonus could be claimed.
+    pub fn last_request(&self) -> &ClockSnapshot {
+        &self.last_request
+    }
+
+    /// Returns the clock snapshot taken when the amiibo bonus was last received,
+    /// or `None` if the bonus was never claimed (or the data was reset).
+    pub fn last_benefit_received(&self) -> Option<&ClockSnapshot> {
+        (self.last_benefit_received.initial_type != 0).then_some(&self.last_benefit_received)
+    }
+
+    /// Returns the steady clock time at which the current penalty ends, if
+    /// there is one.
+    pub fn end_of_penalty(&self) -> Option<&SteadyClockTime> {
+        (self.end_of_penalty.timestamp != 0).then_some(&self.end_of_penalty)
+    }
+
+    /// Returns whether a penalty was in effect when the game last checked the clock.
+    ///
+    /// Penalties are issued if the system clock is moved back after claiming the bonus.
+    /// As steady clock times can't be compared across different clock states, this
+    /// only returns `true` if the penalty end and the last request share the same state.
+    pub fn is_penalty_active(&self) -> bool {
+        self.end_of_penalty().is_some_and(|end| {
+            end.is_comparable(&self.last_request.steady_clock_time)
+                && end.timestamp > self.last_request.steady_clock_time.timestamp
+        })
+    }
+
+    /// Resets the claim context, so that the amiibo bonus can be claimed again.
+    ///
+    /// This clears any penalty and invalidates the last received snapshot. Its timestamps
+    /// are moved to the UNIX epoch, keeping calendar times consistent with POSIX times.
+    pub fn reset(&mut self) {
+        let snapshot = &mut self.last_benefit_received;
+        snapshot
+            .set_time(0)
+            .expect("the UNIX epoch is in the calendar range");
+        snapshot.initial_type = 0;
+        self.end_of_penalty.timestamp = 0;
+    }
+}
+
+impl ClockSnapshot {
+    /// Returns the system clock time, in seconds since the UNIX epoch.
+    pub fn system_time(&self) -> i64 {
+        self.system_time_posix
+    }
+
+    /// Returns the network clock time, in seconds since the UNIX epoch.
+    pub fn network_time(&self) -> i64 {
+        self.network_time_posix
+    }
+
+    /// Returns the system clock time, as a local calendar date.
+    pub fn system_calendar(&self) -> CalendarTime {
+        self.system_time_calendar
+    }
+
+    /// Returns the network clock time, as a local calendar date.
+    pub fn network_calendar(&self) -> CalendarTime {
+        self.network_time_calendar
+    }
+
+    /// Returns the steady clock time when the snapshot was taken.
+    pub fn steady_clock_time(&self) -> &SteadyClockTime {
+        &self.steady_clock_time
+    }
+
+    /// Updates both system and network times to the given POSIX timestamp.
+    ///
+    /// Calendar times and additional calendar info (day of week, day of year)
+    /// are recomputed to match, using the UTC offset already stored in the snapshot.
+    ///
+    /// Times before the UNIX epoch are supported, as long as the local year fits
+    /// in a [`CalendarTime`]. Otherwise, the snapshot is left unchanged.
+    pub fn set_time(&mut self, posix_time: i64) -> Result<(), TimeRangeError> {
+        let system = self.system_calendar_info.local_date(posix_time)?;
+        let network = self.network_calendar_info.local_date(posix_time)?;
+        self.system_time_posix = posix_time;
+        self.network_time_posix = posix_time;
+        self.system_time_calendar = self.system_calendar_info.update(system);
+        self.network_time_calendar = self.network_calendar_info.update(network);
+        Ok(())
+    }
+}
+
+impl SteadyClockTime {
+    /// Returns whether the two time points were taken from the same
+    /// steady clock source, i.e. if their timestamps can be compared.
+    pub fn is_comparable(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl CalendarTime {
+    /// Converts a POSIX timestamp to a calendar time. This does not take
+    /// time zones into account.
+    ///
+    /// Timestamps before the UNIX epoch are supported, but the year must
+    /// fit in a `u16` (i.e. be between 0 and 65535).
+    pub fn from_posix(posix_time: i64) -> Result<Self, TimeRangeError> {
+        let days = posix_time.div_euclid(SECONDS_PER_DAY);
+        let seconds = posix_time.rem_euclid(SECONDS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        Ok(Self {
+            year: year.try_into().map_err(|_| TimeRangeError(posix_time))?,
+            month: month as u8,
+            day: day as u8,
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+        })
+    }
+}
+
+/// A local date, along with the day count it was computed from.
+struct LocalDate {
+    days: i64,
+    calendar: CalendarTime,
+}
+
+impl CalendarAdditionalInfo {
+    /// Returns the local calendar time for the timestamp, using the stored UTC offset.
+    fn local_date(&self, posix_time: i64) -> Result<LocalDate, TimeRangeError> {
+        let local = posix_time
+            .checked_add(i64::from(self.utc_offset_seconds))
+            .ok_or(TimeRangeError(posix_time))?;
+        let calendar = CalendarTime::from_posix(local).map_err(|_| TimeRangeError(posix_time))?;
+        Ok(LocalDate {
+            days: local.div_euclid(SECONDS_PER_DAY),
+            calendar,
+        })
+    }
+
+    /// Updates day of week and day of year, and returns the
+    /// local calendar time.
+    fn update(&mut self, date: LocalDate) -> CalendarTime {
+        let LocalDate { days, calendar } = date;
+        // 1970-01-01 was a Thursday
+        self.day_of_week = (days + 4).rem_euclid(7) as u32;
+        self.day_of_year = (days - days_from_civil(i64::from(calendar.year), 1, 1)) as u32;
+        calendar
+    }
+}
+
+/// Converts a day count relative to 1970-01-01 to a (year, month, day) date.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a date to a day count relative to 1970-01-01.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{days_from_civil, CalendarTime, TimeRangeError};
+    use crate::SaveFile;
+
+    static SRC: &[u8] = include_bytes!("../../../tests/res/save-ch5-v10.sav");
+
+    #[test]
+    fn calendar_from_posix() {
+        let time = CalendarTime::from_posix(1658988502).unwrap();
+        assert_eq!(
+            CalendarTime {
+                year: 2022,
+                month: 7,
+                day: 28,
+                hour: 6,
+                minute: 8,
+                second: 22
+            },
+            time
+        );
+        assert_eq!(1658988502 / 86400, days_from_civil(2022, 7, 28));
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+    }
+
+    #[test]
+    fn calendar_before_epoch() {
+        assert_eq!(
+            CalendarTime {
+                year: 1969,
+                month: 12,
+                day: 31,
+                hour: 23,
+                minute: 59,
+                second: 59
+            },
+            CalendarTime::from_posix(-1).unwrap()
+        );
+        // 0000-01-01T00:00:00
+        let min = days_from_civil(0, 1, 1) * 86400;
+        assert_eq!(0, CalendarTime::from_posix(min).unwrap().year);
+        assert_eq!(
+            Err(TimeRangeError(min - 1)),
+            CalendarTime::from_posix(min - 1)
+        );
+    }
+
+    #[test]
+    fn penalty() {
+        let mut save = SaveFile::from_bytes(SRC).unwrap();
+        let amiibo = &mut save.save_mut().menu_data.amiibo_time_data;
+        let now = amiibo.last_request.steady_clock_time.timestamp;
+
+        amiibo.end_of_penalty.state = amiibo.last_request.steady_clock_time.state;
+        amiibo.end_of_penalty.timestamp = now + 3600;
+        assert!(amiibo.is_penalty_active());
+        amiibo.end_of_penalty.timestamp = now - 1;
+        assert!(!amiibo.is_penalty_active());
+
+        // Different steady clock sources
+        amiibo.end_of_penalty.timestamp = now + 3600;
+        amiibo.end_of_penalty.state[0] ^= 1;
+        assert!(amiibo.end_of_penalty().is_some());
+        assert!(!amiibo.is_penalty_active());
+
+        amiibo.reset();
+        assert!(amiibo.end_of_penalty().is_none());
+        assert!(!amiibo.is_penalty_active());
+    }
+
+    #[test]
+    fn set_time_out_of_range() {
+        let mut save = SaveFile::from_bytes(SRC).unwrap();
+        let snapshot = &mut save.save_mut().menu_data.amiibo_time_data.last_request;
+
+        snapshot.set_time(-86400).unwrap();
+        assert_eq!(-86400, snapshot.system_time());
+        assert_eq!(1969, snapshot.system_calendar().year);
+        assert_eq!(364, snapshot.system_calendar_info.day_of_year);
+
+        assert_eq!(Err(TimeRangeError(i64::MIN)), snapshot.set_time(i64::MIN));
+        assert_eq!(-86400, snapshot.system_time());
+    }
+}
@@ -126,6 +126,96 @@ trait ChronologicalKey: Ord + Copy + crate::io::SaveBin {
     fn is_null(&self) -> bool;
 }
 
+impl ChronologicalData {
+    /// Returns whether the main character at `char_index` has learned the art.
+    ///
+    /// ## Panics
+    /// This function panics if the character index or art ID are out of bounds.
+    pub fn is_character_art_learned(&self, char_index: usize, art_id: usize) -> bool {
+        let index = art_id.checked_sub(1).expect("art ID must be >= 1");
+        self.character_art_status.buf[char_index][index] != 0
+    }
+
+    /// Registers an art as learned by the main character at `char_index`.
+    /// The art appears as the most recently learned one.
+    ///
+    /// This does nothing if the art was already learned.
+    ///
+    /// ## Panics
+    /// This function panics if the character index or art ID are out of bounds.
+    pub fn learn_character_art(&mut self, char_index: usize, art_id: usize) {
+        let index = art_id.checked_sub(1).expect("art ID must be >= 1");
+        let entry = &mut self.character_art_status.buf[char_index][index];
+        if *entry == 0 {
+            self.art_status_max = self.art_status_max.wrapping_add(1);
+            *entry = self.art_status_max;
+        }
+    }
+
+    /// Returns whether the main character at `char_index` has learned the skill.
+    ///
+    /// ## Panics
+    /// This function panics if the character index or skill ID are out of bounds.
+    pub fn is_character_skill_learned(&self, char_index: usize, skill_id: usize) -> bool {
+        let index = skill_id.checked_sub(1).expect("skill ID must be >= 1");
+        self.character_skill_status.buf[char_index][index] != 0
+    }
+
+    /// Registers a skill as learned by the main character at `char_index`.
+    /// The skill appears as the most recently learned one.
+    ///
+    /// This does nothing if the skill was already learned.
+    ///
+    /// ## Panics
+    /// This function panics if the character index or skill ID are out of bounds.
+    pub fn learn_character_skill(&mut self, char_index: usize, skill_id: usize) {
+        let index = skill_id.checked_sub(1).expect("skill ID must be >= 1");
+        let entry = &mut self.character_skill_status.buf[char_index][index];
+        if *entry == 0 {
+            self.skill_status_max = self.skill_status_max.wrapping_add(1);
+            *entry = self.skill_status_max;
+        }
+    }
+}
+
+impl<const R: usize> ChronologicalList<R> {
+    /// Returns whether the entry is registered in the list.
+    ///
+    /// ## Panics
+    /// This function panics if the entry ID is out of bounds.
+    pub fn contains(&self, id: usize) -> bool {
+        self.items.buf[0][id] != 0
+    }
+
+    /// Removes an entry from the list. Other entries keep their order.
+    ///
+    /// ## Panics
+    /// This function panics if the entry ID is out of bounds.
+    pub fn remove(&mut self, id: usize) {
+        self.items.buf[0][id] = 0;
+    }
+}
+
+impl<const R: usize, const C: usize> ChronologicalTable<R, C> {
+    /// Returns whether the entry is registered in the given column.
+    ///
+    /// ## Panics
+    /// This function panics if the column or entry ID are out of bounds.
+    pub fn contains(&self, column: usize, id: usize) -> bool {
+        self.items.buf[column][id] != 0
+    }
+
+    /// Registers a new entry in the given column. The new entry appears at
+    /// the start of the order.
+    ///
+    /// ## Panics
+    /// This function panics if the column or entry ID are out of bounds.
+    pub fn insert(&mut self, column: usize, id: usize) {
+        self.max = self.max.wrapping_add(1);
+        self.items.buf[column][id] = self.max;
+    }
+}
+
 impl<const R: usize> ChronologicalOrder for ChronologicalList<R> {
     fn cmp_entries(&self, id_a: usize, id_b: usize) -> Ordering {
         NullsLastReverse(self.items.buf[0][id_a]).cmp(&NullsLastReverse(self.items.buf[0][id_b]))
@@ -148,10 +238,14 @@ impl ChronologicalOrder for Inventory {
     fn cmp_entries(&self, id_a: usize, id_b: usize) -> Ordering {
         let id_a: u16 = id_a.try_into().unwrap();
         let id_b: u16 = id_b.try_into().unwrap();
-        let (ty_a, ty_b) = (
+        // Items with an unknown type are ordered last
+        let (ty_a, ty_b) = match (
             ItemType::get_by_item_id(id_a),
             ItemType::get_by_item_id(id_b),
-        );
+        ) {
+            (Some(a), Some(b)) => (a, b),
+            (a, b) => return a.is_none().cmp(&b.is_none()),
+        };
         let (slot_a, slot_b) = (
             self.slots(ty_a)
                 .iter()
@@ -170,7 +264,9 @@ impl ChronologicalOrder for Inventory {
         let id_a: u16 = id_a.try_into().unwrap();
         let id_b: u16 = id_b.try_into().unwrap();
 
-        let (slot_a, slot_b) = self.split_slots_mut(id_a, id_b);
+        let Some((slot_a, slot_b)) = self.split_slots_mut(id_a, id_b) else {
+            return;
+        };
 
         let b_chrono = slot_b.chronological_id();
         slot_b.set_chronological_id(slot_a.chronological_id());
@@ -179,12 +275,15 @@ impl ChronologicalOrder for Inventory {
 
     fn insert(&mut self, id: usize) {
         let id: u16 = id.try_into().unwrap();
+        let Some(item_type) = ItemType::get_by_item_id(id) else {
+            return;
+        };
         // Item table will also wrap on overflow.
         let max = self.chronological_id_max.wrapping_add(1);
         self.chronological_id_max = max;
         // Assume item has already been registered.
         let slot = self
-            .slots_mut(ItemType::get_by_item_id(id.try_into().unwrap()))
+            .slots_mut(item_type)
             .iter_mut()
             .find(|s| s.item_id() == id)
             .expect("item not yet registered");
@@ -1,8 +1,17 @@
 use recordkeeper_macros::SaveBin;
 
-use crate::character::{class::ClassAccessory, CHARACTER_MAX};
+use crate::{
+    character::{
+        slot::{Slot, SlotMut},
+        CHARACTER_MAX,
+    },
+    item::DlcManualSlot,
+    SaveData,
+};
 
 pub const DLC4_ENEMYPEDIA_MAX_EACH: usize = 200;
+/// Number of Unity Combo pairs in Future Redeemed
+pub const DLC4_PAIR_MAX: usize = 3;
 
 #[derive(SaveBin, Debug)]
 pub struct Dlc4 {
@@ -22,5 +31,67 @@ pub struct Dlc4 {
 #[size(512)]
 pub struct Dlc4ExtraInventory {
     /// Likely indexed by class ID
-    battle_manual: Box<[ClassAccessory; 64]>,
+    battle_manual: Box<[DlcManualSlot; 64]>,
+}
+
+impl Dlc4 {
+    /// Returns the battle manual a character has equipped for a class.
+    ///
+    /// ## Panics
+    /// Panics if the character or class IDs are out of bounds.
+    pub fn battle_manual(&self, char_id: usize, class_id: usize) -> Slot<DlcManualSlot> {
+        Slot(self.extra_inventory(char_id).battle_manual[class_index(class_id)])
+    }
+
+    /// Returns a mutable view of the battle manual a character has
+    /// equipped for a class.
+    ///
+    /// ## Panics
+    /// Panics if the character or class IDs are out of bounds.
+    pub fn battle_manual_mut(
+        &mut self,
+        char_id: usize,
+        class_id: usize,
+    ) -> SlotMut<'_, DlcManualSlot> {
+        let index = char_id.checked_sub(1).expect("char ID must be >= 1");
+        SlotMut(&mut self.extra_inventory[index].battle_manual[class_index(class_id)])
+    }
+
+    /// Returns the battle manuals equipped by a character, indexed by
+    /// class ID - 1.
+    ///
+    /// ## Panics
+    /// Panics if the character ID is out of bounds.
+    pub fn battle_manuals(&self, char_id: usize) -> impl Iterator<Item = Slot<DlcManualSlot>> + '_ {
+        self.extra_inventory(char_id)
+            .battle_manual
+            .iter()
+            .map(|slot| Slot(*slot))
+    }
+
+    fn extra_inventory(&self, char_id: usize) -> &Dlc4ExtraInventory {
+        &self.extra_inventory[char_id.checked_sub(1).expect("char ID must be >= 1")]
+    }
+}
+
+impl SaveData {
+    /// Returns the `BTL_Pair` IDs of the Unity Combo pairs (Future Redeemed).
+    pub fn dlc4_pairs(&self) -> [u8; DLC4_PAIR_MAX] {
+        self.dlc4_pairs
+    }
+
+    /// Changes the Unity Combo pair at the given index.
+    ///
+    /// The ID is not checked against `BTL_Pair`, and a character may end up
+    /// in more than one pair.
+    ///
+    /// ## Panics
+    /// Panics if the index is out of bounds.
+    pub fn set_dlc4_pair(&mut self, index: usize, pair_id: u8) {
+        self.dlc4_pairs[index] = pair_id;
+    }
+}
+
+fn class_index(class_id: usize) -> usize {
+    class_id.checked_sub(1).expect("class ID must be >= 1")
 }
@@ -73,6 +73,18 @@ impl AccessoryCrafting {
         }
     }
 
+    /// Moves craft data offsets after the accessory inventory was reordered.
+    ///
+    /// `order[new_slot]` is the old slot index of the item now at `new_slot`.
+    /// Slots past the end of `order` are left without craft data.
+    pub(crate) fn reorder_slots(&mut self, order: &[usize]) {
+        let old = self.offsets.clone();
+        self.offsets.fill(u16::MAX);
+        for (new_slot, &old_slot) in order.iter().enumerate() {
+            self.offsets[new_slot] = old[old_slot];
+        }
+    }
+
     /// Creates or replaces a crafted data slot for the given item slot.
     ///
     /// ## Errors
@@ -61,6 +61,20 @@ impl<'a> ItemEditor<'a> {
         }
     }
 
+    /// Marks the item as favorite, or removes the mark.
+    ///
+    /// This does nothing if the slot is empty.
+    pub fn set_favorite(&mut self, favorite: bool) {
+        self.slot.set_favorite(favorite);
+    }
+
+    /// Marks the item as new, or marks it as seen.
+    ///
+    /// This does nothing if the slot is empty.
+    pub fn set_new(&mut self, new: bool) {
+        self.slot.set_new(new);
+    }
+
     /// Clears the item slot.
     ///
     /// If the item is a crafted accessory, its extra data will also be cleared.
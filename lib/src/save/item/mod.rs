@@ -2,6 +2,7 @@ use recordkeeper_macros::SaveBin;
 use thiserror::Error;
 
 use crate::{
+    character::slot::{EmptySlot, EmptySlotMut, Slot, SlotMut},
     dlc::{CraftItemData, CRAFTED_ITEM_ID},
     SaveData,
 };
@@ -9,6 +10,7 @@ use crate::{
 pub const ITEM_ACCESSORY_MAX: usize = 1500;
 
 pub mod edit;
+pub mod sort;
 
 #[derive(SaveBin, Debug)]
 pub struct Inventory {
@@ -59,7 +61,12 @@ enum SlotFlags {
     HasCraftData = 1 << 3,
 }
 
-#[derive(SaveBin, Debug)]
+/// Future Redeemed battle manual slot, see [`Dlc4::battle_manual`].
+///
+/// Like class accessories, manuals refer to a slot in the inventory.
+///
+/// [`Dlc4::battle_manual`]: crate::dlc::Dlc4::battle_manual
+#[derive(SaveBin, Debug, Default, Clone, Copy)]
 pub struct DlcManualSlot {
     item_id: u16,
     inventory_slot_index: u16,
@@ -84,6 +91,10 @@ pub enum ItemType {
 pub struct TypeFromIntError(u32);
 
 impl Inventory {
+    /// Returns the inventory slots for the given item type.
+    ///
+    /// Collectopedia entries are not stored in the inventory (their progress is
+    /// tracked through flags instead), so an empty slice is returned for them.
     pub fn slots(&self, item_type: ItemType) -> &[ItemSlot] {
         match item_type {
             ItemType::Cylinder => &*self.cylinders,
@@ -94,7 +105,7 @@ impl Inventory {
             ItemType::Precious => &*self.key_items,
             ItemType::Exchange => &*self.exchange,
             ItemType::Extra => &*self.extra,
-            t => panic!("unsupported item type {t:?}"),
+            ItemType::Collectopedia => &[],
         }
     }
 
@@ -108,18 +119,34 @@ impl Inventory {
             ItemType::Precious => &mut *self.key_items,
             ItemType::Exchange => &mut *self.exchange,
             ItemType::Extra => &mut *self.extra,
-            t => panic!("unsupported item type {t:?}"),
+            ItemType::Collectopedia => &mut [],
         }
     }
 
+    /// Marks all items of the given type as favorite, or removes the mark.
+    pub fn set_all_favorite(&mut self, item_type: ItemType, favorite: bool) {
+        self.slots_mut(item_type)
+            .iter_mut()
+            .for_each(|slot| slot.set_favorite(favorite));
+    }
+
+    /// Marks all items of the given type as new, or marks them as seen.
+    pub fn set_all_new(&mut self, item_type: ItemType, new: bool) {
+        self.slots_mut(item_type)
+            .iter_mut()
+            .for_each(|slot| slot.set_new(new));
+    }
+
+    /// Returns the slots for both item IDs, or [`None`] if either item ID
+    /// has an unknown item type.
     pub(crate) fn split_slots_mut(
         &mut self,
         id_a: u16,
         id_b: u16,
-    ) -> (&mut ItemSlot, &mut ItemSlot) {
+    ) -> Option<(&mut ItemSlot, &mut ItemSlot)> {
         let (ty_a, ty_b) = (
-            ItemType::get_by_item_id(id_a),
-            ItemType::get_by_item_id(id_b),
+            ItemType::get_by_item_id(id_a)?,
+            ItemType::get_by_item_id(id_b)?,
         );
         let (idx_a, idx_b) = (
             self.slots(ty_a)
@@ -135,10 +162,10 @@ impl Inventory {
             let slots = self.slots_mut(ty_a);
             if idx_a > idx_b {
                 let (before_a, after_a) = slots.split_at_mut(idx_a);
-                (&mut after_a[0], &mut before_a[idx_b])
+                Some((&mut after_a[0], &mut before_a[idx_b]))
             } else {
                 let (before_b, after_b) = slots.split_at_mut(idx_b);
-                (&mut before_b[idx_a], &mut after_b[0])
+                Some((&mut before_b[idx_a], &mut after_b[0]))
             }
         } else {
             let (slots_a, slots_b) = (
@@ -147,12 +174,21 @@ impl Inventory {
             );
             // SAFETY: the two slot arrays are different, so we are just splitting
             // the borrow. The indexing is still performed safely with bound checks.
-            unsafe { (&mut (&mut *slots_a)[idx_a], &mut (&mut *slots_b)[idx_b]) }
+            unsafe { Some((&mut (&mut *slots_a)[idx_a], &mut (&mut *slots_b)[idx_b])) }
         }
     }
 }
 
 impl ItemSlot {
+    pub(crate) const EMPTY: Self = Self {
+        item_id: 0,
+        slot_index: 0,
+        item_type: 0,
+        chronological_id: 0,
+        amount: 0,
+        flags: 0,
+    };
+
     /// Returns the slot's positional index.
     pub fn index(&self) -> u16 {
         self.slot_index
@@ -182,6 +218,35 @@ impl ItemSlot {
         self.flags & (SlotFlags::Active as u8) != 0
     }
 
+    /// Returns whether the player has marked the item as favorite.
+    pub fn is_favorite(&self) -> bool {
+        self.flags & (SlotFlags::Favorite as u8) != 0
+    }
+
+    /// Returns whether the item is marked as new, i.e. it has the small
+    /// circle icon in the inventory menu.
+    pub fn is_new(&self) -> bool {
+        self.flags & (SlotFlags::New as u8) != 0
+    }
+
+    /// Marks the item as favorite, or removes the mark.
+    ///
+    /// This does nothing if the slot is empty.
+    pub fn set_favorite(&mut self, favorite: bool) {
+        if self.is_valid() {
+            self.set_flag(SlotFlags::Favorite, favorite);
+        }
+    }
+
+    /// Marks the item as new, or marks it as seen.
+    ///
+    /// This does nothing if the slot is empty.
+    pub fn set_new(&mut self, new: bool) {
+        if self.is_valid() {
+            self.set_flag(SlotFlags::New, new);
+        }
+    }
+
     /// Returns whether the slot hosts a crafted accessory. (DLC3)
     pub fn is_crafted_accessory(&self) -> bool {
         self.is_valid()
@@ -198,15 +263,93 @@ impl ItemSlot {
         self.chronological_id
     }
 
+    fn set_flag(&mut self, flag: SlotFlags, value: bool) {
+        if value {
+            self.flags |= flag as u8;
+        } else {
+            self.flags &= !(flag as u8);
+        }
+    }
+
     pub(crate) fn set_chronological_id(&mut self, chronological_id: u32) {
         self.chronological_id = chronological_id;
     }
 }
 
+impl DlcManualSlot {
+    pub fn item_id(&self) -> u16 {
+        self.item_id
+    }
+
+    pub fn inventory_slot_index(&self) -> u16 {
+        self.inventory_slot_index
+    }
+
+    /// Returns the type of the equipped item, or [`None`] if the slot
+    /// is empty or the item type is invalid.
+    pub fn item_type(&self) -> Option<ItemType> {
+        ItemType::try_from(u32::from(self.item_type)).ok()
+    }
+
+    pub(crate) fn inventory_type(&self) -> Option<ItemType> {
+        ItemType::try_from(u32::from(self.item_type))
+            .ok()
+            .filter(|&t| t != ItemType::Collectopedia)
+    }
+}
+
+impl<'a> SlotMut<'a, DlcManualSlot> {
+    /// Equips the item in the given inventory slot.
+    ///
+    /// If the inventory slot is empty, the manual slot will also be emptied.
+    pub fn set_from_inventory(&mut self, inventory_slot: &ItemSlot) {
+        let out = &mut self.0;
+        if !inventory_slot.is_valid() {
+            self.set_empty();
+            return;
+        }
+        out.inventory_slot_index = inventory_slot.index();
+        out.item_id = inventory_slot.item_id();
+        out.item_type = inventory_slot.item_type() as u16;
+    }
+}
+
+impl EmptySlot for Slot<DlcManualSlot> {
+    fn is_empty(&self) -> bool {
+        self.0.item_id == 0 || self.0.item_type == 0
+    }
+}
+
+impl<'a> EmptySlot for SlotMut<'a, DlcManualSlot> {
+    fn is_empty(&self) -> bool {
+        Slot(*self.0).is_empty()
+    }
+}
+
+impl<'a> EmptySlotMut for SlotMut<'a, DlcManualSlot> {
+    fn set_empty(&mut self) {
+        *self.0 = DlcManualSlot::default();
+    }
+}
+
 impl ItemType {
-    pub fn get_by_item_id(item_id: u16) -> Self {
-        //match item_id {}
-        todo!()
+    /// Returns the item type for an item ID, or [`None`] if the item ID
+    /// is not part of any known range.
+    ///
+    /// Each `ITM_*` table has its own range of 2000 item IDs.
+    pub fn get_by_item_id(item_id: u16) -> Option<Self> {
+        Some(match item_id {
+            1..=2000 => Self::Accessory,
+            2001..=4000 => Self::Collection,
+            4001..=6000 => Self::Collectopedia,
+            6001..=8000 => Self::Cylinder,
+            8001..=10000 => Self::Exchange,
+            10001..=12000 => Self::Extra,
+            12001..=14000 => Self::Gem,
+            14001..=16000 => Self::Info,
+            16001..=18000 => Self::Precious,
+            _ => return None,
+        })
     }
 
     pub fn lang_id(self) -> &'static str {
@@ -0,0 +1,93 @@
+//! Inventory compaction and sorting.
+
+use std::cmp::Reverse;
+
+use crate::SaveData;
+
+use super::{ItemSlot, ItemType};
+
+/// Order of inventory slots after [`SaveData::sort_inventory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "strum", derive(strum::EnumIter))]
+pub enum ItemSortOrder {
+    /// Only remove empty slots, keeping the current order
+    Compact,
+    /// Ascending item ID
+    ItemId,
+    /// Grouped by item type (the `ITM_*` table of the item ID), then ascending
+    /// item ID. Items with an unknown type go last.
+    Type,
+    /// Most recently obtained items first
+    Chronological,
+}
+
+impl SaveData {
+    /// Sorts the inventory slots for an item type, moving all empty slots
+    /// to the end.
+    ///
+    /// Everything that refers to inventory slots is updated to follow the
+    /// items: equipped accessories (characters and party formations),
+    /// Future Redeemed battle manuals, and crafted accessory data.
+    ///
+    /// References that already pointed to the wrong item are left untouched,
+    /// use [`SaveData::repair_accessories`] to fix them.
+    ///
+    /// This does nothing for Collectopedia entries, as they are not stored in
+    /// the inventory.
+    pub fn sort_inventory(&mut self, item_type: ItemType, order: ItemSortOrder) {
+        if item_type == ItemType::Collectopedia {
+            return;
+        }
+        let slots = self.inventory.slots(item_type);
+
+        // sorted[new_index] = old_index
+        let mut sorted: Vec<usize> = (0..slots.len()).filter(|&i| slots[i].is_valid()).collect();
+        match order {
+            ItemSortOrder::Compact => {}
+            ItemSortOrder::ItemId => sorted.sort_by_key(|&i| slots[i].item_id()),
+            ItemSortOrder::Type => sorted.sort_by_key(|&i| {
+                let item_id = slots[i].item_id();
+                let item_type = ItemType::get_by_item_id(item_id);
+                (item_type.is_none(), item_type.map(|t| t as u32), item_id)
+            }),
+            ItemSortOrder::Chronological => sorted.sort_by_key(|&i| {
+                // Items without a chronological ID go last
+                let id = slots[i].chronological_id();
+                (id == 0, Reverse(id))
+            }),
+        }
+
+        let mut new_index = vec![None; slots.len()];
+        for (new, &old) in sorted.iter().enumerate() {
+            new_index[old] = Some(new);
+        }
+
+        // Only references that currently point to the right item are moved
+        let references: Vec<_> = self
+            .equipped_items()
+            .into_iter()
+            .filter(|&(_, item)| item.item_type == Some(item_type) && self.is_linked(item))
+            .collect();
+
+        let old_slots = slots.to_vec();
+        let slots = self.inventory.slots_mut(item_type);
+        slots.fill(ItemSlot::EMPTY);
+        for (new, &old) in sorted.iter().enumerate() {
+            slots[new] = ItemSlot {
+                slot_index: new.try_into().unwrap(),
+                ..old_slots[old]
+            };
+        }
+
+        if item_type == ItemType::Accessory {
+            self.accessory_crafting.reorder_slots(&sorted);
+        }
+
+        for (location, item) in references {
+            let new = new_index[usize::from(item.slot_index)].expect("linked slot is valid");
+            let inventory_slot = self.inventory.slots(item_type)[new];
+            self.equipment_slot_mut(location)
+                .set_from_inventory(&inventory_slot);
+        }
+    }
+}
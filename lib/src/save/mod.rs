@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use crate::error::SaveError;
 use crate::item::Inventory;
 use crate::save::character::{Character, Ouroboros, CHARACTER_MAX, OUROBOROS_MAX};
@@ -15,12 +17,12 @@ use crate::menu::MenuData;
 use crate::util::FixVec;
 use recordkeeper_macros::SaveBin;
 
-use self::character::CharacterSets;
-use self::chrono::ChronologicalData;
+use self::character::{party::PartyError, CharacterSets};
+use self::chrono::{ChronologicalData, ChronologicalOrder};
 use self::enemy::SoulHackAchievements;
 use self::field::FieldConfig;
 use self::flags::BitFlags;
-use dlc::{AccessoryCrafting, ChallengeBattle, Dlc4, PowAugment, POW_AUGMENT_NUM};
+use dlc::{AccessoryCrafting, ChallengeBattle, Dlc4, PowAugment, DLC4_PAIR_MAX, POW_AUGMENT_NUM};
 
 pub mod character;
 pub mod chrono;
@@ -89,7 +91,12 @@ pub struct SaveData {
     #[loc(0x6c0)]
     pub ship_pos: Pos,
 
-    /// Starts at 0, index for `party_characters`
+    /// Starts at 0, index for `party_characters`. Only the lowest byte is
+    /// the index: the sample save in `lib/tests/res` stores `0x3ff5be05` with
+    /// a 7-member party, controlling the 6th member. Use [`Party`] to keep it
+    /// valid when editing the party.
+    ///
+    /// [`Party`]: crate::character::party::Party
     pub controlled_character_idx: u32,
 
     #[loc(0x710)]
@@ -111,7 +118,7 @@ pub struct SaveData {
 
     /// ID for `BTL_Pair`
     #[loc(0x53c38)]
-    dlc4_pairs: [u8; 3],
+    dlc4_pairs: [u8; DLC4_PAIR_MAX],
 
     #[loc(0x53c78)]
     pub inventory: Inventory,
@@ -211,6 +218,87 @@ impl SaveData {
         self.is_flag_set(SaveFlag::Dlc4)
     }
 
+    /// Returns the IDs of the six main characters (Noah to Sena, or
+    /// Matthew to Rex in Future Redeemed saves).
+    ///
+    /// Tables that only track main characters, like [`ChronologicalData::character_classes`],
+    /// are indexed by position in this range.
+    pub fn main_character_ids(&self) -> RangeInclusive<usize> {
+        if self.is_dlc4() {
+            36..=41
+        } else {
+            1..=6
+        }
+    }
+
+    /// Fully unlocks a character, so that they can always be added to the party.
+    ///
+    /// Other than updating [`CharacterSets`], this registers the character in the
+    /// unlock order, and (for heroes) unlocks their class for all main characters,
+    /// like the game does.
+    ///
+    /// ## Panics
+    /// Panics if the character ID is out of bounds.
+    pub fn unlock_character(&mut self, char_id: usize) {
+        let index = char_id.checked_sub(1).expect("character ID must be >= 1");
+        self.character_sets.set_status(char_id, true, true, false);
+        let unlocked = &mut self.chronological_data.unlocked_characters;
+        if !unlocked.contains(index) {
+            unlocked.insert(index);
+        }
+
+        let main_characters = self.main_character_ids();
+        let class_id = usize::from(self.characters[index].selected_class);
+        if main_characters.contains(&char_id) || class_id == 0 {
+            return;
+        }
+        for (column, main_id) in main_characters.enumerate() {
+            let classes = &mut self.chronological_data.character_classes;
+            if !classes.contains(column, class_id - 1) {
+                classes.insert(column, class_id - 1);
+            }
+            let class = self.characters[main_id - 1].class_data_mut(class_id);
+            if class.level == 0 {
+                class.level = 1;
+            }
+        }
+    }
+
+    /// Makes a character only available temporarily, e.g. for heroes that join
+    /// the party during a quest.
+    ///
+    /// The character is removed from the unlock order.
+    ///
+    /// ## Panics
+    /// Panics if the character ID is out of bounds.
+    pub fn make_character_temporary(&mut self, char_id: usize) {
+        let index = char_id.checked_sub(1).expect("character ID must be >= 1");
+        self.character_sets.set_status(char_id, true, false, true);
+        self.chronological_data.unlocked_characters.remove(index);
+    }
+
+    /// Locks a character, so that they can no longer be added to the party.
+    ///
+    /// The character is removed from the unlock order and from the active party.
+    /// Classes unlocked through the character are kept.
+    ///
+    /// If the character is the only party member, [`PartyError::Empty`] is returned
+    /// and the save is left unchanged.
+    ///
+    /// ## Panics
+    /// Panics if the character ID is out of bounds.
+    pub fn lock_character(&mut self, char_id: usize) -> Result<(), PartyError> {
+        let index = char_id.checked_sub(1).expect("character ID must be >= 1");
+        let party_id = char_id.try_into().expect("character ID out of bounds");
+        match self.party_mut().remove(party_id) {
+            Ok(()) | Err(PartyError::NotInParty(_)) => {}
+            Err(e) => return Err(e),
+        }
+        self.character_sets.set_status(char_id, false, false, false);
+        self.chronological_data.unlocked_characters.remove(index);
+        Ok(())
+    }
+
     /// Returns whether there currently is a mid-run gauntlet save.
     pub fn has_gauntlet_save(&self) -> bool {
         self.is_flag_set(SaveFlag::Gauntlet) && self.challenge_battle.gauntlet_save().active
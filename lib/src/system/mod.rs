@@ -5,6 +5,13 @@ use crate::{error::SaveError, flags::BitFlags};
 pub(crate) const SYSTEM_VERSION: u32 = 2;
 pub(crate) const SYSTEM_MAGIC: [u8; 4] = [0x74, 0x60, 0xab, 0xe6];
 
+pub const SETTINGS_MAX: usize = 80;
+pub const SAVE_SLOT_MAX: usize = 5;
+pub const COSTUME_MAX: usize = 52;
+pub const EVENT_THEATER_MAX: usize = 313 * 16;
+/// Number of characters that can have costumes
+pub const COSTUME_CHARACTER_MAX: usize = 8;
+
 #[derive(SaveBin, Debug)]
 pub struct SystemData {
     #[assert(SYSTEM_MAGIC)]
@@ -16,11 +23,13 @@ pub struct SystemData {
 
     /// Index is `option_id` from `MNU_option_*`
     #[loc(0x10)]
-    global_settings: [u16; 80],
+    global_settings: [u16; SETTINGS_MAX],
 
+    /// Purpose unknown
     #[loc(0xb8)]
     some_flags: BitFlags<1, 30>,
 
+    /// Index is ID for the Event Theater table. 0 is locked, 1 is unlocked.
     #[loc(0x138)]
     event_theater_flags: BitFlags<2, 313>,
 
@@ -30,18 +39,20 @@ pub struct SystemData {
 
     /// Index is ID in `RSC_PcCostumeOpen`. In each byte, each bit is for each character.
     #[loc(0x66c)]
-    costume_characters: [u8; 52],
+    costume_characters: [u8; COSTUME_MAX],
 
     #[loc(0x6a0)]
     _unk: u64, // new game count?
     /// One for each slot
-    save_counter: [u64; 5],
+    save_counter: [u64; SAVE_SLOT_MAX],
     /// One for each slot
-    load_counter: [u64; 5],
+    load_counter: [u64; SAVE_SLOT_MAX],
     pub continue_counter: u64,
     pub settings_save_counter: u64,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "strum", derive(strum::EnumIter))]
 pub enum SystemFlag {
     /// Whether the main game has been cleared on any file.
     MainGameClear = 0,
@@ -72,11 +83,158 @@ impl SystemData {
         self.flags.set(flag as usize, u8::from(value).into())
     }
 
+    /// Returns the value for a global setting.
+    ///
+    /// `option_id` is the `option_id` field in the `MNU_option_*` tables.
+    ///
+    /// ## Panics
+    /// Panics if `option_id` is out of bounds (`>= SETTINGS_MAX`).
     pub fn get_setting(&self, option_id: usize) -> u16 {
         self.global_settings[option_id]
     }
 
+    /// Updates the value for a global setting.
+    ///
+    /// `option_id` is the `option_id` field in the `MNU_option_*` tables.
+    ///
+    /// ## Panics
+    /// Panics if `option_id` is out of bounds (`>= SETTINGS_MAX`).
     pub fn set_setting(&mut self, option_id: usize, value: u16) {
         self.global_settings[option_id] = value;
     }
+
+    /// Returns the number of times the game was saved to the given slot.
+    ///
+    /// ## Panics
+    /// Panics if the slot index is out of bounds (`>= SAVE_SLOT_MAX`).
+    pub fn save_count(&self, slot: usize) -> u64 {
+        self.save_counter[slot]
+    }
+
+    /// Updates the number of times the game was saved to the given slot.
+    ///
+    /// ## Panics
+    /// Panics if the slot index is out of bounds (`>= SAVE_SLOT_MAX`).
+    pub fn set_save_count(&mut self, slot: usize, count: u64) {
+        self.save_counter[slot] = count;
+    }
+
+    /// Returns the number of times a save was loaded from the given slot.
+    ///
+    /// ## Panics
+    /// Panics if the slot index is out of bounds (`>= SAVE_SLOT_MAX`).
+    pub fn load_count(&self, slot: usize) -> u64 {
+        self.load_counter[slot]
+    }
+
+    /// Updates the number of times a save was loaded from the given slot.
+    ///
+    /// ## Panics
+    /// Panics if the slot index is out of bounds (`>= SAVE_SLOT_MAX`).
+    pub fn set_load_count(&mut self, slot: usize, count: u64) {
+        self.load_counter[slot] = count;
+    }
+
+    /// Returns whether the given event can be viewed in the Event Theater.
+    ///
+    /// ## Panics
+    /// Panics if the event ID is out of bounds (`>= EVENT_THEATER_MAX`).
+    pub fn is_event_unlocked(&self, event_id: usize) -> bool {
+        self.event_theater_flags
+            .get(event_id)
+            .expect("event ID out of bounds")
+            != 0
+    }
+
+    /// Updates the Event Theater unlock status for the given event.
+    ///
+    /// ## Panics
+    /// Panics if the event ID is out of bounds (`>= EVENT_THEATER_MAX`).
+    pub fn set_event_unlocked(&mut self, event_id: usize, unlocked: bool) {
+        self.event_theater_flags.set(event_id, u32::from(unlocked));
+    }
+
+    /// Unlocks all the given events in the Event Theater.
+    ///
+    /// ## Panics
+    /// Panics if any event ID is out of bounds (`>= EVENT_THEATER_MAX`).
+    pub fn unlock_events(&mut self, event_ids: impl IntoIterator<Item = usize>) {
+        for id in event_ids {
+            self.set_event_unlocked(id, true);
+        }
+    }
+
+    /// Locks every event in the Event Theater.
+    pub fn lock_all_events(&mut self) {
+        for id in 0..EVENT_THEATER_MAX {
+            self.set_event_unlocked(id, false);
+        }
+    }
+
+    /// Returns the IDs of all events that are currently unlocked in
+    /// the Event Theater.
+    pub fn unlocked_events(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..EVENT_THEATER_MAX).filter(|&id| self.is_event_unlocked(id))
+    }
+
+    /// Returns whether the given costume is unlocked for the character, for all
+    /// save files.
+    ///
+    /// `costume_id` is the row ID in `RSC_PcCostumeOpen`, and `character_id`
+    /// is the 1-based index of the character within that row.
+    ///
+    /// ## Panics
+    /// Panics if either ID is out of bounds (`costume_id >= COSTUME_MAX`,
+    /// `character_id` not in `1..=COSTUME_CHARACTER_MAX`)
+    pub fn is_costume_unlocked(&self, costume_id: usize, character_id: usize) -> bool {
+        let bit = Self::costume_bit(character_id);
+        self.costume_characters[costume_id] & bit != 0
+    }
+
+    /// Updates the unlock status for the given costume and character.
+    ///
+    /// `costume_id` is the row ID in `RSC_PcCostumeOpen`, and `character_id`
+    /// is the 1-based index of the character within that row.
+    ///
+    /// ## Panics
+    /// Panics if either ID is out of bounds (`costume_id >= COSTUME_MAX`,
+    /// `character_id` not in `1..=COSTUME_CHARACTER_MAX`)
+    pub fn set_costume_unlocked(&mut self, costume_id: usize, character_id: usize, unlocked: bool) {
+        let bit = Self::costume_bit(character_id);
+        let byte = &mut self.costume_characters[costume_id];
+        if unlocked {
+            *byte |= bit;
+        } else {
+            *byte &= !bit;
+        }
+    }
+
+    /// Returns the value of a flag from the second system flag table.
+    ///
+    /// The purpose of these flags is currently unknown.
+    ///
+    /// ## Panics
+    /// Panics if the index is out of bounds.
+    pub fn is_some_flag_set(&self, index: usize) -> bool {
+        self.some_flags
+            .get(index)
+            .expect("flag index out of bounds")
+            != 0
+    }
+
+    /// Updates a flag from the second system flag table.
+    ///
+    /// ## Panics
+    /// Panics if the index is out of bounds.
+    pub fn set_some_flag(&mut self, index: usize, value: bool) {
+        self.some_flags.set(index, u32::from(value));
+    }
+
+    fn costume_bit(character_id: usize) -> u8 {
+        assert!(
+            (1..=COSTUME_CHARACTER_MAX).contains(&character_id),
+            "character ID out of bounds"
+        );
+        1 << (character_id - 1)
+    }
 }
@@ -0,0 +1,44 @@
+use recordkeeper::{chrono::amiibo::CalendarTime, SaveFile};
+
+static SRC: &[u8] = include_bytes!("res/save-ch5-v10.sav");
+
+#[test]
+pub fn read_claim_context() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let amiibo = &save.save().menu_data.amiibo_time_data;
+
+    let last_request = amiibo.last_request();
+    assert_eq!(1658988502, last_request.system_time());
+    assert_eq!(
+        CalendarTime::from_posix(1658988502).unwrap(),
+        last_request.system_calendar()
+    );
+
+    let received = amiibo.last_benefit_received().unwrap();
+    assert_eq!(1658988491, received.system_time());
+    assert_eq!(
+        CalendarTime::from_posix(1658988491).unwrap(),
+        received.system_calendar()
+    );
+    assert!(received
+        .steady_clock_time()
+        .is_comparable(last_request.steady_clock_time()));
+
+    assert!(amiibo.end_of_penalty().is_none());
+    assert!(!amiibo.is_penalty_active());
+}
+
+#[test]
+pub fn reset_claim_context() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    save.save_mut().menu_data.amiibo_time_data.reset();
+    save.write().unwrap();
+
+    let save = SaveFile::from_bytes(save.bytes()).unwrap();
+    let amiibo = &save.save().menu_data.amiibo_time_data;
+    assert!(amiibo.last_benefit_received().is_none());
+    assert!(amiibo.end_of_penalty().is_none());
+    assert!(!amiibo.is_penalty_active());
+    // The last request is kept
+    assert_eq!(1658988502, amiibo.last_request().system_time());
+}
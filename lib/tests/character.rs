@@ -0,0 +1,413 @@
+use recordkeeper::{
+    character::{
+        accessory::{AccessoryFix, AccessoryLocation},
+        dlc4::{Dlc4Slots, LockedSlot},
+        formation::{FormationIssue, FormationName, FormationReport, PartyFormation},
+        party::PartyError,
+        Character,
+    },
+    dlc::CRAFTED_ITEM_ID,
+    item::{edit::ItemEditor, ItemType},
+    SaveFile,
+};
+
+static SRC: &[u8] = include_bytes!("res/save-ch5-v10.sav");
+
+const LEVEL_EXP: [u32; 5] = [0, 100, 250, 500, 1000];
+
+#[test]
+pub fn level_exp_consistency() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let mut character: Character = save.save().characters[0];
+
+    character.set_level(3, &LEVEL_EXP);
+    assert_eq!(250, character.exp);
+    assert!(character.is_level_consistent(&LEVEL_EXP));
+
+    character.set_exp(499, &LEVEL_EXP);
+    assert_eq!(3, character.level);
+    character.set_exp(5000, &LEVEL_EXP);
+    assert_eq!(5, character.level);
+    character.set_exp(0, &LEVEL_EXP);
+    assert_eq!(1, character.level);
+
+    character.level = 4;
+    assert!(!character.is_level_consistent(&LEVEL_EXP));
+}
+
+#[test]
+pub fn unlock_lock_character() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+
+    // Hero 11 hasn't joined yet, their class is 10
+    save.unlock_character(11);
+    assert_eq!(Some(1), save.character_sets.permanent_characters.get(10));
+    assert!(save.chronological_data.unlocked_characters.contains(10));
+    for (column, main_id) in save.main_character_ids().enumerate() {
+        assert!(save
+            .chronological_data
+            .character_classes
+            .contains(column, 9));
+        assert_eq!(1, save.characters[main_id - 1].class_data(10).rank());
+    }
+
+    save.make_character_temporary(11);
+    assert_eq!(Some(1), save.character_sets.selectable_characters.get(10));
+    assert_eq!(Some(0), save.character_sets.permanent_characters.get(10));
+    assert_eq!(Some(1), save.character_sets.temporary_characters.get(10));
+    assert!(!save.chronological_data.unlocked_characters.contains(10));
+
+    save.lock_character(11).unwrap();
+    assert_eq!(Some(0), save.character_sets.selectable_characters.get(10));
+    assert_eq!(Some(0), save.character_sets.temporary_characters.get(10));
+}
+
+#[test]
+pub fn lock_party_member() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let members = save.party_mut().members().collect::<Vec<_>>();
+    let controlled = save.party_mut().controlled().unwrap();
+    let locked = members[0];
+    assert_ne!(locked, controlled);
+
+    save.lock_character(usize::from(locked)).unwrap();
+    let party = save.party_mut();
+    assert!(party.members().eq(members[1..].iter().copied()));
+    assert_eq!(Some(controlled), party.controlled());
+
+    save.lock_character(usize::from(controlled)).unwrap();
+    assert!(!save.party_mut().members().any(|id| id == controlled));
+    assert!(save.party_mut().controlled().is_some());
+
+    // The last party member can't be locked
+    for &id in &members[2..] {
+        if id != controlled {
+            save.lock_character(usize::from(id)).unwrap();
+        }
+    }
+    let last = members[1];
+    assert_eq!(vec![last], save.party_mut().members().collect::<Vec<_>>());
+    assert_eq!(
+        Err(PartyError::Empty),
+        save.lock_character(usize::from(last))
+    );
+    assert_eq!(Ok(()), save.party_mut().validate());
+    assert!(save
+        .chronological_data
+        .unlocked_characters
+        .contains(usize::from(last) - 1));
+}
+
+#[test]
+pub fn controlled_index_low_byte() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    // 7 party members, the 6th is controlled
+    assert_eq!(0x3ff5be05, save.controlled_character_idx);
+    assert_eq!(7, save.party_characters.len());
+    assert_eq!(Some(6), save.party_mut().controlled());
+
+    save.party_mut().set_controlled(1).unwrap();
+    assert_eq!(0x3ff5be00, save.controlled_character_idx);
+}
+
+#[test]
+pub fn party_invariants() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let mut party = save.party_mut();
+
+    assert_eq!(Ok(()), party.validate());
+    assert_eq!(Some(6), party.controlled());
+    assert_eq!(Err(PartyError::Full), party.add(7));
+
+    party.remove(21).unwrap();
+    assert_eq!(Err(PartyError::Duplicate(1)), party.add(1));
+    assert_eq!(Err(PartyError::NotSelectable(11)), party.add(11));
+    party.add(7).unwrap();
+    party.remove(5).unwrap();
+    assert_eq!(Err(PartyError::TooManyHeroes), party.add(8));
+
+    party.move_member(4, 0);
+    assert_eq!(vec![6, 1, 2, 3, 4, 7], party.members().collect::<Vec<_>>());
+    assert_eq!(Some(6), party.controlled());
+
+    party.remove(6).unwrap();
+    assert_eq!(Some(1), party.controlled());
+    assert_eq!(Err(PartyError::NotInParty(6)), party.set_controlled(6));
+    assert_eq!(Ok(()), party.validate());
+}
+
+#[test]
+pub fn party_guests() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let mut party = save.party_mut();
+
+    assert_eq!(vec![625, 626, 628, 34], party.guests().collect::<Vec<_>>());
+    assert_eq!(Err(PartyError::DuplicateGuest(34)), party.add_guest(34));
+    party.remove_guest(626).unwrap();
+    assert_eq!(
+        Err(PartyError::GuestNotInParty(626)),
+        party.remove_guest(626)
+    );
+    party.add_guest(1).unwrap();
+    assert_eq!(vec![625, 628, 34, 1], party.guests().collect::<Vec<_>>());
+}
+
+#[test]
+pub fn apply_formation() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let formation = PartyFormation::from_save(save, FormationName::default());
+
+    save.characters[0].costume_id = 5;
+    save.characters[0].selected_class = 1;
+    save.ouroboros[0].art_ids[0] = 0;
+    save.party_mut().remove(21).unwrap();
+
+    let report = formation.apply_to(save, |_, _| true);
+    assert_eq!(FormationReport::default(), report);
+    assert_eq!(0, save.characters[0].costume_id);
+    assert_eq!(18, save.characters[0].selected_class);
+    assert_eq!(
+        formation.ouroboros(1).unwrap().art_ids,
+        save.ouroboros[0].art_ids
+    );
+    assert_eq!(
+        vec![1, 2, 3, 4, 5, 6, 21],
+        save.party_characters.iter().copied().collect::<Vec<_>>()
+    );
+
+    save.lock_character(21).unwrap();
+    let report = formation.apply_to(save, |_, _| true);
+    assert_eq!(
+        vec![FormationIssue::CharacterNotSelectable(21)],
+        report.issues
+    );
+    assert_eq!(6, save.party_characters.len());
+    assert_eq!(Ok(()), save.party_mut().validate());
+}
+
+#[test]
+pub fn apply_formation_issues() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let mut formation = PartyFormation::from_save(save, FormationName::default());
+
+    // Noah's class 18 is the only one with gem category 20 equipped
+    let report = formation.apply_to(save, |_, category_id| category_id != 20);
+    assert_eq!(
+        vec![FormationIssue::MissingGem {
+            char_id: 1,
+            class_id: 18,
+            slot: 2,
+            category_id: 20,
+        }],
+        report.issues
+    );
+    let gems = save.characters[0].class_data(18).gems();
+    assert_eq!(
+        vec![Some(17), Some(15), None],
+        gems.take(3).map(|slot| slot.get()).collect::<Vec<_>>()
+    );
+
+    formation.ouroboros[0].ouroboros_id = 7;
+    let report = formation.apply_to(save, |_, _| true);
+    assert_eq!(vec![FormationIssue::UnknownOuroboros(7)], report.issues);
+
+    formation.ouroboros[0].ouroboros_id = 1;
+    formation.character_mut(1).current_class = 65;
+    formation.character_mut(2).character_id = 1000;
+    let report = formation.apply_to(save, |_, _| true);
+    assert_eq!(
+        vec![
+            FormationIssue::UnknownClass {
+                char_id: 1,
+                class_id: 65
+            },
+            FormationIssue::UnknownCharacter(1000)
+        ],
+        report.issues
+    );
+    assert_eq!(18, save.characters[0].selected_class);
+
+    // None of the characters can be added, the party is left untouched
+    let party = save.party_characters.clone();
+    formation.party.clear();
+    formation.party.try_push(11).unwrap();
+    save.characters[0].costume_id = 5;
+    let report = formation.apply_to(save, |_, _| true);
+    assert_eq!(vec![FormationIssue::EmptyParty], report.issues);
+    assert!(party.iter().eq(save.party_characters.iter()));
+    assert_eq!(5, save.characters[0].costume_id);
+}
+
+#[test]
+pub fn repair_accessories() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    assert!(save.dangling_accessories().is_empty());
+
+    // Accessory 517 in slot 191 is equipped by several classes. Move it to
+    // a free slot
+    let free = save
+        .inventory
+        .slots(ItemType::Accessory)
+        .iter()
+        .position(|s| !s.is_valid())
+        .unwrap();
+    ItemEditor::new(save, ItemType::Accessory, 191).clear();
+    let dangling = save.dangling_accessories();
+    assert!(!dangling.is_empty());
+    assert!(dangling
+        .iter()
+        .all(|d| d.item_id == 517 && d.slot_index == 191));
+
+    ItemEditor::new(save, ItemType::Accessory, free)
+        .set_item_id(517)
+        .unwrap();
+    let fixes = save.repair_accessories();
+    assert_eq!(dangling.len(), fixes.len());
+    assert!(fixes.iter().all(|f| matches!(
+        f,
+        AccessoryFix::Relinked { slot_index, .. } if usize::from(*slot_index) == free
+    )));
+    assert_eq!(
+        free,
+        usize::from(
+            save.characters[0]
+                .class_data(18)
+                .accessory_slot(0)
+                .get()
+                .unwrap()
+                .slot_index()
+        )
+    );
+    assert!(save.dangling_accessories().is_empty());
+
+    // Without the item, references are cleared
+    ItemEditor::new(save, ItemType::Accessory, free).clear();
+    let fixes = save.repair_accessories();
+    assert!(fixes
+        .iter()
+        .all(|f| matches!(f, AccessoryFix::Cleared { .. })));
+    assert!(save.characters[0]
+        .class_data(18)
+        .accessory_slot(0)
+        .get()
+        .is_none());
+    assert!(save.dangling_accessories().is_empty());
+}
+
+#[test]
+pub fn repair_crafted_accessories() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let mut free = save
+        .inventory
+        .slots(ItemType::Accessory)
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| !s.is_valid())
+        .map(|(i, _)| i);
+    let (equipped, other) = (free.next().unwrap(), free.next().unwrap());
+
+    for index in [equipped, other] {
+        ItemEditor::new(save, ItemType::Accessory, index)
+            .set_item_id(CRAFTED_ITEM_ID)
+            .unwrap();
+    }
+    let item = save.inventory.slots(ItemType::Accessory)[equipped];
+    save.characters[0]
+        .class_data_mut(1)
+        .accessory_slot_mut(0)
+        .set_from_inventory(&item);
+    assert!(save.dangling_accessories().is_empty());
+
+    // Another crafted accessory has different stats, so the reference is cleared
+    ItemEditor::new(save, ItemType::Accessory, equipped).clear();
+    let location = AccessoryLocation::Class {
+        char_id: 1,
+        class_id: 1,
+        slot: 0,
+    };
+    assert_eq!(
+        vec![AccessoryFix::Cleared { location }],
+        save.repair_accessories()
+    );
+    assert!(save.characters[0]
+        .class_data(1)
+        .accessory_slot(0)
+        .get()
+        .is_none());
+    assert!(save.dangling_accessories().is_empty());
+}
+
+#[test]
+pub fn dlc4_slot_expansions() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    let matthew = &mut save.characters[35];
+    let class_id = usize::from(matthew.selected_class.max(1));
+
+    // Only the first master art slot is unlocked
+    assert_eq!(
+        Dlc4Slots {
+            master_arts: 1,
+            gems: 1,
+            accessories: 1
+        },
+        matthew.dlc4_slots()
+    );
+
+    assert!(matthew.dlc4_locked_slots(class_id).is_empty());
+
+    let class = matthew.class_data_mut(class_id);
+    class.art_slot_mut(3).set(Some(1));
+    class.gem_slot_mut(1).set(Some(0));
+    assert_eq!(
+        vec![LockedSlot::Art(3), LockedSlot::Gem(1)],
+        matthew.dlc4_locked_slots(class_id)
+    );
+
+    matthew.unlock_dlc4_slots();
+    assert_eq!(Dlc4Slots::MAX, matthew.dlc4_slots());
+    assert!(matthew.dlc4_locked_slots(class_id).is_empty());
+}
+
+#[test]
+pub fn battle_manuals() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+    assert!(save.dlc4.battle_manuals(36).all(|m| m.get().is_none()));
+
+    let (index, item) = save
+        .inventory
+        .slots(ItemType::Precious)
+        .iter()
+        .enumerate()
+        .find(|(_, s)| s.is_valid())
+        .map(|(i, s)| (i, *s))
+        .unwrap();
+    save.dlc4
+        .battle_manual_mut(36, 37)
+        .set_from_inventory(&item);
+    let manual = save.dlc4.battle_manual(36, 37).get().unwrap();
+    assert_eq!(item.item_id(), manual.item_id());
+    assert_eq!(Some(ItemType::Precious), manual.item_type());
+    assert!(save.dangling_accessories().is_empty());
+
+    ItemEditor::new(save, ItemType::Precious, index).clear();
+    let location = AccessoryLocation::BattleManual {
+        char_id: 36,
+        class_id: 37,
+    };
+    assert_eq!(
+        vec![AccessoryFix::Cleared { location }],
+        save.repair_accessories()
+    );
+    assert!(save.dlc4.battle_manual(36, 37).get().is_none());
+}
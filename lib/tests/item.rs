@@ -0,0 +1,214 @@
+use std::cmp::Ordering;
+
+use recordkeeper::{
+    chrono::ChronologicalOrder,
+    dlc::{CraftItemData, CRAFTED_ITEM_ID},
+    item::{edit::ItemEditor, sort::ItemSortOrder, ItemType},
+    SaveData, SaveFile,
+};
+
+static SRC: &[u8] = include_bytes!("res/save-ch5-v10.sav");
+
+const ITEM_TYPES: [ItemType; 8] = [
+    ItemType::Cylinder,
+    ItemType::Gem,
+    ItemType::Collection,
+    ItemType::Info,
+    ItemType::Accessory,
+    ItemType::Precious,
+    ItemType::Exchange,
+    ItemType::Extra,
+];
+
+#[test]
+pub fn item_type_by_id() {
+    let save = SaveFile::from_bytes(SRC).unwrap();
+    let inventory = &save.save().inventory;
+
+    for item_type in ITEM_TYPES {
+        for slot in inventory.slots(item_type).iter().filter(|s| s.is_valid()) {
+            assert_eq!(Some(item_type), ItemType::get_by_item_id(slot.item_id()));
+        }
+    }
+    assert_eq!(None, ItemType::get_by_item_id(0));
+    assert_eq!(None, ItemType::get_by_item_id(18001));
+}
+
+#[test]
+pub fn item_chronology() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let inventory = &mut save.save_mut().inventory;
+
+    // One item of each type in the save, in inventory order
+    let mut items: Vec<usize> = ITEM_TYPES
+        .into_iter()
+        .filter_map(|ty| inventory.slots(ty).iter().find(|s| s.is_valid()))
+        .map(|s| usize::from(s.item_id()))
+        .collect();
+    assert_eq!(6, items.len());
+
+    items.sort_by(|&a, &b| inventory.cmp_entries(a, b));
+    for pair in items.windows(2) {
+        assert_eq!(Ordering::Less, inventory.cmp_entries(pair[0], pair[1]));
+    }
+
+    // Most and least recent items trade places
+    let (first, last) = (items[0], items[items.len() - 1]);
+    inventory.swap(first, last);
+    assert_eq!(Ordering::Greater, inventory.cmp_entries(first, last));
+    items.sort_by(|&a, &b| inventory.cmp_entries(a, b));
+    assert_eq!(last, items[0]);
+    assert_eq!(first, items[items.len() - 1]);
+
+    // Newly registered items appear first
+    inventory.insert(first);
+    items.sort_by(|&a, &b| inventory.cmp_entries(a, b));
+    assert_eq!(first, items[0]);
+}
+
+#[test]
+pub fn compact_inventory() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+
+    let item_ids = |save: &SaveData| -> Vec<u16> {
+        save.inventory
+            .slots(ItemType::Collection)
+            .iter()
+            .filter(|s| s.is_valid())
+            .map(|s| s.item_id())
+            .collect()
+    };
+    let before = item_ids(save);
+    save.sort_inventory(ItemType::Collection, ItemSortOrder::Compact);
+    assert_eq!(before, item_ids(save));
+
+    let slots = save.inventory.slots(ItemType::Collection);
+    assert!(slots[..before.len()].iter().all(|s| s.is_valid()));
+    assert!(slots[before.len()..].iter().all(|s| !s.is_valid()));
+    for (i, slot) in slots.iter().enumerate().take(before.len()) {
+        assert_eq!(i, usize::from(slot.index()));
+    }
+}
+
+#[test]
+pub fn sort_collectopedia() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+
+    // Not stored in the inventory, nothing to sort
+    save.sort_inventory(ItemType::Collectopedia, ItemSortOrder::ItemId);
+    assert!(save.inventory.slots(ItemType::Collectopedia).is_empty());
+    assert!(save.dangling_accessories().is_empty());
+}
+
+#[test]
+pub fn sort_inventory_references() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+
+    // (amount, craft data) for every accessory, keyed by what is equipped
+    let equipped = |save: &SaveData| -> Vec<(u16, u16, Option<CraftItemData>)> {
+        let slots = save.inventory.slots(ItemType::Accessory);
+        save.characters
+            .iter()
+            .flat_map(|c| (1..=64).map(move |class_id| c.class_data(class_id)))
+            .flat_map(|class| class.accessories())
+            .filter_map(|slot| slot.get())
+            .filter(|acc| acc.is_from_inventory())
+            .map(|acc| {
+                let slot = &slots[usize::from(acc.slot_index())];
+                assert_eq!(acc.bdat_id(), slot.item_id());
+                (
+                    slot.item_id(),
+                    slot.amount(),
+                    slot.craft_data(save).copied(),
+                )
+            })
+            .collect()
+    };
+
+    // Add and equip a crafted accessory, in the first empty slot
+    let crafted = save
+        .inventory
+        .slots(ItemType::Accessory)
+        .iter()
+        .position(|s| !s.is_valid())
+        .unwrap();
+    let mut editor = ItemEditor::new(save, ItemType::Accessory, crafted);
+    editor.set_item_id(CRAFTED_ITEM_ID).unwrap();
+    editor.craft_data_mut().unwrap().level = 5;
+    save.inventory.insert(CRAFTED_ITEM_ID.into());
+    let inventory_slot = save.inventory.slots(ItemType::Accessory)[crafted];
+    save.characters[0]
+        .class_data_mut(1)
+        .accessory_slot_mut(0)
+        .set_from_inventory(&inventory_slot);
+
+    assert!(save.dangling_accessories().is_empty());
+    let before = equipped(save);
+    assert!(before
+        .iter()
+        .any(|(_, _, craft)| craft.is_some_and(|c| c.level == 5)));
+    assert!(!before.is_empty());
+
+    for order in [
+        ItemSortOrder::ItemId,
+        ItemSortOrder::Type,
+        ItemSortOrder::Chronological,
+    ] {
+        save.sort_inventory(ItemType::Accessory, order);
+        assert!(save.dangling_accessories().is_empty());
+        assert_eq!(before, equipped(save));
+    }
+
+    let slots = save.inventory.slots(ItemType::Accessory);
+    let valid = slots.iter().filter(|s| s.is_valid()).count();
+    // Chronological: most recent first
+    for pair in slots[..valid].windows(2) {
+        let (a, b) = (
+            usize::from(pair[0].item_id()),
+            usize::from(pair[1].item_id()),
+        );
+        if a != b {
+            assert!(save.inventory.cmp_entries(a, b).is_le());
+        }
+    }
+}
+
+#[test]
+pub fn favorite_and_new_markers() {
+    let mut save = SaveFile::from_bytes(SRC).unwrap();
+    let save = save.save_mut();
+
+    let slots = save.inventory.slots(ItemType::Collection);
+    let item = slots.iter().position(|s| s.is_valid()).unwrap();
+    let empty = slots.iter().position(|s| !s.is_valid()).unwrap();
+
+    save.inventory.set_all_new(ItemType::Collection, false);
+    save.inventory.set_all_favorite(ItemType::Collection, false);
+    let slots = save.inventory.slots(ItemType::Collection);
+    assert!(slots.iter().all(|s| !s.is_new() && !s.is_favorite()));
+
+    let mut editor = ItemEditor::new(save, ItemType::Collection, item);
+    editor.set_new(true);
+    editor.set_favorite(true);
+    let slot = &save.inventory.slots(ItemType::Collection)[item];
+    assert!(slot.is_new() && slot.is_favorite());
+
+    // Empty slots can't be marked
+    let mut editor = ItemEditor::new(save, ItemType::Collection, empty);
+    editor.set_new(true);
+    editor.set_favorite(true);
+    let slot = &save.inventory.slots(ItemType::Collection)[empty];
+    assert!(!slot.is_new() && !slot.is_favorite());
+
+    save.inventory.set_all_new(ItemType::Collection, true);
+    let slots = save.inventory.slots(ItemType::Collection);
+    assert!(slots.iter().all(|s| s.is_new() == s.is_valid()));
+
+    let slot = &mut save.inventory.slots_mut(ItemType::Collection)[item];
+    slot.set_new(false);
+    slot.set_favorite(false);
+    assert!(!slot.is_new() && !slot.is_favorite());
+}
@@ -13,3 +13,32 @@ pub fn write_back_no_changes() {
     save.write().unwrap();
     assert_eq!(SRC, save.bytes());
 }
+
+#[test]
+pub fn read_counters_and_unlocks() {
+    let save = SystemFile::from_bytes(SRC).unwrap();
+    let save = save.save();
+    assert_eq!(3757, save.save_count(0));
+    assert_eq!(236, save.load_count(1));
+    assert!(save.is_costume_unlocked(1, 6));
+    assert!(!save.is_costume_unlocked(0, 1));
+    assert!(!save.is_costume_unlocked(29, 1));
+    assert!(save.is_event_unlocked(1));
+    assert!(!save.is_event_unlocked(0));
+}
+
+#[test]
+pub fn lock_unlock_events() {
+    let mut save = SystemFile::from_bytes(SRC).unwrap();
+    let system = save.save_mut();
+    assert_eq!(256, system.unlocked_events().count());
+
+    system.lock_all_events();
+    assert_eq!(0, system.unlocked_events().count());
+
+    system.unlock_events([1, 5, 400]);
+    assert_eq!(
+        vec![1, 5, 400],
+        system.unlocked_events().collect::<Vec<_>>()
+    );
+}
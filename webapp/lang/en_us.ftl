@@ -44,12 +44,16 @@ menu_category_meta = Metadata
 # Dangerous settings that should not be changed by the average user
 menu_category_danger = Danger Zone
 
+# Data stored in the system file, shared by all save files
+menu_category_system = System Data
+
 menu_base_characters = Characters
 menu_base_ouroboros = Ouroboros
 menu_base_items = Items
 menu_base_field = Field
 menu_base_quests = Quests
 menu_base_ums = Unique Monsters
+menu_base_collepedia = Collectopedia
 menu_base_formations = Party Formations
 menu_base_chrono = Chronological Data
 
@@ -66,6 +70,11 @@ menu_meta_meta = Save Info
 
 menu_danger_flags = Flags
 
+menu_system_meta = System Info
+menu_system_settings = Settings
+menu_system_costumes = Costumes
+menu_system_theater = Event Theater
+
 ## Save info translations
 
 hours = Hours
@@ -118,11 +127,20 @@ item_type = Item Type
 item_slot_index = Slot ID
 item_item = Item
 item_amount = Amount
+item_favorite = Favorite
+item_new = New
 item_actions = Actions
 
 # You are searching item slots for items
 item_search = Search item slots...
 item_first_empty = Go to empty slot
+item_give_all = Give all
+item_mark_all_seen = Mark all as seen
+item_give_all_confirm = { $added } items will be added and { $refilled } refilled to their max amount.
+    { $no_space ->
+        [0] {""}
+        *[other] { $no_space } items don't fit in the inventory.
+    } Continue?
 
 # Item types
 
@@ -192,14 +210,23 @@ quest_task_talk = Talk
 
 character_character = Character
 character_party = Party Setup
+character_guests = Guests
+character_dlc4_pairs = Unity Combo Pairs
+character_dlc4_pair_none = (None)
+character_dlc4_pair_unknown = Unknown pair #{ $id }.
+character_dlc4_pair_shared = Character #{ $id } can only be part of one pair.
 character_level = Level
 character_exp = EXP
+character_level_mismatch = Level and EXP don't match.
 character_bexp = Bonus EXP
 character_selected_class = Edit/Selected Class
 
 character_set_selectable = Selectable
 character_set_permanent = Permanent
 character_set_temp = Temporary
+character_set_unlock = Unlock
+character_set_make_temp = Make Temporary
+character_set_lock = Lock
 
 # The character's selected costume
 character_costume = Costume
@@ -255,6 +282,19 @@ character_accessory =
 character_class_cp = CP
 character_class_unlock = Unlock Points
 character_class_rank = Rank
+character_battle_manual = Battle Manual
+
+character_build = Build
+character_build_export = Export
+character_build_import = Import
+character_build_import_issues = The build was imported, but { $count } { $count ->
+    [one] accessory is
+    *[other] accessories are
+} no longer in the inventory and { $count ->
+    [one] was
+    *[other] were
+} left unequipped.
+character_build_wrong_class = The build is for a different class, switch to that class to import it.
 
 character_flag_unload_dlc_costume = DLC Costume Unloaded
 character_flag_eyepatch = Show Eyepatch
@@ -291,6 +331,14 @@ ouroboros_tree_skill_unlock = Unlock Skill
 ouroboros_tree_art_unlock = Unlock Art
 ouroboros_tree_skill_upgrade = Upgrade Skill
 ouroboros_tree_art_upgrade = Upgrade Art
+ouroboros_tree_cost = ({ $sp } SP)
+ouroboros_tree_unlock_all = Unlock All
+ouroboros_tree_issues = { $count ->
+    [one] 1 unlocked node is missing prerequisites.
+    *[other] { $count } unlocked nodes are missing prerequisites.
+}
+ouroboros_tree_sp_error = Not enough SP: { $needed } SP needed, { $available } SP available.
+ouroboros_tree_node_error = Unknown skill tree node #{ $id }.
 
 
 ## Field screen
@@ -362,11 +410,32 @@ enemy_soul_hack_progress = Upgrade Progress
 enemy_soul_hack_upgraded = Upgraded
 
 
+## Collectopedia screen
+
+collepedia_id = ID
+collepedia_entry = Entry
+collepedia_item = Required Item
+collepedia_turned_in = Turned In
+collepedia_rewards = Rewards
+collepedia_required = Required Entries
+collepedia_reward = Reward
+collepedia_claimed = Claimed
+collepedia_complete = Complete region
+collepedia_progress = { $turned_in }/{ $total } entries turned in
+collepedia_reward_error = This reward requires { $required } turned in entries, but only { $turned_in } have been turned in.
+collepedia_unknown = Unknown Collectopedia ID: { $id }
+
+
 ## Party Formations screens
 
 formation_back = Back to list
 formation_edit = Edit
 formation_delete = Delete
+formation_apply = Apply
+formation_apply_issues = The formation was applied, but { $count } { $count ->
+    [one] part
+    *[other] parts
+} could not be restored (e.g. missing accessories).
 formation_create = New from save
 formation_copy = Copy existing
 formation_empty = Empty
@@ -452,3 +521,44 @@ chrono_tab_classes = Classes
 chrono_tab_items = Items
 chrono_tab_quests = Quests
 chrono_tab_npc = NPC
+
+## Event Theater screen
+
+event_theater_id = ID
+event_theater_chapter = Chapter
+event_theater_name = Name
+event_theater_unlocked = Unlocked
+event_theater_unlock_all = Unlock All
+event_theater_lock_all = Lock All
+
+## System file screens
+
+system_flags = Global Progress
+system_flag_clear = Main game cleared
+system_flag_clear_ngp = Main game cleared (NG+)
+system_flag_costumes = Costumes enabled
+system_flag_amiibo_1 = Shulk amiibo rewards claimed
+system_flag_amiibo_2 = Pyra amiibo rewards claimed
+system_flag_amiibo_3 = Mythra amiibo rewards claimed
+system_flag_dlc4_clear = Future Redeemed cleared
+system_flag_dlc4_clear_ngp = Future Redeemed cleared (NG+)
+system_flag_amiibo_4 = Extra amiibo costume support
+
+system_counters = Counters
+system_counter_slot = Slot
+system_counter_save = Save Count
+system_counter_load = Load Count
+system_counter_continue = Continue Count
+system_counter_settings = Settings Save Count
+
+system_setting_id = ID
+system_setting_name = Setting
+system_setting_value = Value
+system_setting_out_of_bounds = Not stored in the system file
+
+system_costume_id = ID
+system_costume_name = Costume
+system_costume_unlocked = Unlocked
+system_costume_out_of_bounds = Not stored in the system file
+system_costume_unlock_all = Unlock All
+system_costume_error = Costume { $id } can't be stored in the system file, no costumes were unlocked.
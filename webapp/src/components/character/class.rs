@@ -1,4 +1,5 @@
 use game_data::{
+    build::ClassBuild,
     character::{Art, Class, Skill},
     item::GemCategory,
 };
@@ -9,7 +10,8 @@ use recordkeeper::{
     },
     SaveData,
 };
-use ybc::{Control, Field, Tile};
+use web_sys::HtmlTextAreaElement;
+use ybc::{Button, Buttons, Control, Field, Tile};
 use yew::prelude::*;
 
 use crate::{
@@ -18,7 +20,9 @@ use crate::{
         edit::{editor, NumberInput},
     },
     data::Data,
+    dialog::{DialogLayout, DialogQueue, Severity},
     lang::Text,
+    save::SaveContext,
 };
 
 #[derive(Properties, PartialEq)]
@@ -27,6 +31,11 @@ pub struct ClassEditorProps {
     pub stats: bool,
 }
 
+#[derive(Properties, PartialEq)]
+struct ClassBuildProps {
+    accessor: ClassAccessor,
+}
+
 #[derive(Properties, PartialEq)]
 pub struct ClassProps {
     pub char_id: usize,
@@ -180,10 +189,115 @@ pub fn ClassEditor(props: &ClassEditorProps) -> Html {
                     })}
                 </Tile>
             </Tile>
+            <Tile classes={classes!("is-parent")}>
+                <ClassBuildEditor accessor={accessor} />
+            </Tile>
         </>
     }
 }
 
+/// Exports and imports class builds as JSON, to share them between saves.
+#[function_component]
+fn ClassBuildEditor(props: &ClassBuildProps) -> Html {
+    let data = use_context::<Data>().unwrap();
+    let save_context = use_context::<SaveContext>().unwrap();
+    let dialog_context = use_context::<DialogQueue>().unwrap();
+    let text = use_state(String::new);
+
+    let accessor = props.accessor;
+
+    let export = {
+        let save_context = save_context.clone();
+        let text = text.clone();
+        Callback::from(move |_: MouseEvent| {
+            let save = save_context.get();
+            let save = save.get_save();
+            let build = ClassBuild::from_class(
+                accessor.class_id(save),
+                accessor.class_data(save),
+                save,
+                &data.game().characters,
+                &data.game().items,
+                Some(data.lang()),
+            );
+            text.set(serde_json::to_string_pretty(&build).unwrap());
+        })
+    };
+
+    let import = {
+        let text = text.clone();
+        Callback::from(move |_: MouseEvent| {
+            let build: ClassBuild = match serde_json::from_str(&text) {
+                Ok(build) => build,
+                Err(e) => {
+                    dialog_context.dispatch(Some(
+                        DialogLayout::Ok {
+                            title: None,
+                            message: html!(<p>{e.to_string()}</p>),
+                            severity: Severity::Error,
+                        }
+                        .into(),
+                    ));
+                    return;
+                }
+            };
+            if build.class.id != accessor.class_id(save_context.get().get_save()) {
+                dialog_context.dispatch(Some(
+                    DialogLayout::Ok {
+                        title: None,
+                        message: html!(<Text path="character_build_wrong_class" />),
+                        severity: Severity::Error,
+                    }
+                    .into(),
+                ));
+                return;
+            }
+            let dialog_context = dialog_context.clone();
+            save_context.edit(move |save| {
+                let mut class = *accessor.class_data(save);
+                let issues = build.apply_to_class(&mut class, &save.inventory);
+                *accessor.class_data_mut(save) = class;
+                if !issues.is_empty() {
+                    let count = issues.len();
+                    dialog_context.dispatch(Some(
+                        DialogLayout::Ok {
+                            title: None,
+                            message: html!(<Text path="character_build_import_issues" args={vec![("count".into(), count.into())]} />),
+                            severity: Severity::Warning,
+                        }
+                        .into(),
+                    ))
+                }
+            })
+        })
+    };
+
+    let on_input = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            text.set(input.value());
+        })
+    };
+
+    html! {
+        <Field classes={classes!("is-flex-grow-1")}>
+            <label class="label"><Text path="character_build" /></label>
+            <Control>
+                <textarea class="textarea is-family-monospace" value={(*text).clone()} oninput={on_input} />
+            </Control>
+            <Buttons classes={classes!("mt-2")}>
+                <Button onclick={export}>
+                    <Text path="character_build_export" />
+                </Button>
+                <Button onclick={import}>
+                    <Text path="character_build_import" />
+                </Button>
+            </Buttons>
+        </Field>
+    }
+}
+
 pub fn art_to_id(art: &Art) -> Option<u16> {
     Some(art.id.try_into().unwrap())
 }
@@ -197,6 +311,16 @@ fn gem_category_to_id(gem: &GemCategory) -> Option<u8> {
 }
 
 impl ClassAccessor {
+    /// Returns the ID of the class being edited.
+    pub fn class_id(&self, save: &SaveData) -> usize {
+        match self {
+            ClassAccessor::Character { class, .. } => *class,
+            ClassAccessor::Formation { formation, char } => save.party_formations[*formation]
+                .character(*char)
+                .map_or(0, |c| c.current_class.into()),
+        }
+    }
+
     pub fn class_data<'s>(&self, save: &'s SaveData) -> &'s CharacterClass {
         match self {
             ClassAccessor::Character { char, class } => save.characters[*char].class_data(*class),
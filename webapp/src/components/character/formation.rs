@@ -24,7 +24,7 @@ use crate::{
         select::{Selector, UpdateSelector},
     },
     data::Data,
-    dialog::{DialogLayout, DialogQueue},
+    dialog::{DialogLayout, DialogQueue, Severity},
     lang::Text,
     routes::formation::FormationProps,
     save::SaveContext,
@@ -236,6 +236,7 @@ pub fn FormationCardEmpty(props: &FormationProps) -> Html {
 pub fn FormationCardPresent(props: &FormationStateProps) -> Html {
     let data = use_context::<Data>().unwrap();
     let save_context = use_context::<SaveContext>().unwrap();
+    let dialog_context = use_context::<DialogQueue>().unwrap();
 
     let name_editor = FormationNameId {
         formation: props.id,
@@ -261,6 +262,32 @@ pub fn FormationCardPresent(props: &FormationStateProps) -> Html {
         })
     };
 
+    let apply_callback = {
+        let save_context = save_context.clone();
+        let id = props.id;
+        let items = &data.game().items;
+        Callback::from(move |_: MouseEvent| {
+            let dialog_context = dialog_context.clone();
+            save_context.edit(move |save| {
+                let formation = save.party_formations[id].clone();
+                let report = formation.apply_to(save, |inventory, category_id| {
+                    items.gem_level(inventory, category_id).is_some()
+                });
+                if !report.issues.is_empty() {
+                    let count = report.issues.len();
+                    dialog_context.dispatch(Some(
+                        DialogLayout::Ok {
+                            title: None,
+                            message: html!(<Text path="formation_apply_issues" args={vec![("count".into(), count.into())]} />),
+                            severity: Severity::Warning,
+                        }
+                        .into(),
+                    ))
+                }
+            })
+        })
+    };
+
     html! {
         <Card classes="recordkeeper-formation-card">
             <CardContent>
@@ -286,6 +313,7 @@ pub fn FormationCardPresent(props: &FormationStateProps) -> Html {
             </CardContent>
             <CardFooter>
                 <a class="card-footer-item" onclick={edit_callback}><Text path="formation_edit" /></a>
+                <a class="card-footer-item" onclick={apply_callback}><Text path="formation_apply" /></a>
                 <a class="card-footer-item" onclick={delete_callback}><Text path="formation_delete" /></a>
             </CardFooter>
         </Card>
@@ -5,11 +5,13 @@ use game_data::{
 use recordkeeper::{character::class::CharacterClass, flags::BitFlags, SaveData};
 use strum::{EnumIter, IntoEnumIterator};
 use web_sys::HtmlSelectElement;
-use ybc::{Control, Field, Notification, Tile};
+use ybc::{Button, Buttons, Control, Field, Notification, Tile};
 use yew::prelude::*;
 
 use crate::{
-    components::character::{appearance::Appearance, class::ClassEditor, stats::CharacterStats},
+    components::character::{
+        appearance::Appearance, class::ClassEditor, slot::ManualInput, stats::CharacterStats,
+    },
     components::{
         character::class::ClassAccessor,
         edit::{editor, CheckboxInput},
@@ -63,9 +65,22 @@ pub fn CharacterEditor(props: &CharacterProps) -> Html {
 
     let char_idx = props.char_id.checked_sub(1).unwrap();
     let class_id = save.get().get_save().characters[char_idx].selected_class;
+    let is_dlc4 = save.get().get_save().is_dlc4();
 
     let accessor = CharacterAccessor::Save { idx: char_idx };
 
+    let char_id = props.char_id;
+    let status_callback = |op: fn(&mut SaveData, usize)| {
+        let save = save.clone();
+        Callback::from(move |_: MouseEvent| save.edit(move |save| op(save, char_id)))
+    };
+    let lock_callback = {
+        let save = save.clone();
+        Callback::from(move |_: MouseEvent| {
+            save.try_edit(move |save| save.lock_character(char_id).map_err(Into::into))
+        })
+    };
+
     html! {
         <>
             <Notification>
@@ -77,6 +92,19 @@ pub fn CharacterEditor(props: &CharacterProps) -> Html {
                             </CheckboxInput<CharacterSetEditor>>
                         </Control>
                     })}
+                    <Control>
+                        <Buttons classes={classes!("are-small")}>
+                            <Button onclick={status_callback(SaveData::unlock_character)}>
+                                <Text path="character_set_unlock" />
+                            </Button>
+                            <Button onclick={status_callback(SaveData::make_character_temporary)}>
+                                <Text path="character_set_make_temp" />
+                            </Button>
+                            <Button onclick={lock_callback}>
+                                <Text path="character_set_lock" />
+                            </Button>
+                        </Buttons>
+                    </Control>
                 </Field>
                 <Tile classes={classes!("notification")}>
                     <CharacterStats ..*props />
@@ -85,6 +113,12 @@ pub fn CharacterEditor(props: &CharacterProps) -> Html {
             </Notification>
             <Notification>
                 <ClassEditor accessor={accessor.into_class(class_id as usize)} stats={true} />
+                {(is_dlc4 && class_id != 0).then(|| html! {
+                    <Field>
+                        <label class="label"><Text path="character_battle_manual" /></label>
+                        <ManualInput char_id={props.char_id} class_id={class_id as usize} />
+                    </Field>
+                })}
             </Notification>
         </>
     }
@@ -1,10 +1,20 @@
-use game_data::character::Character;
-use recordkeeper::{character::PARTY_MAX, util::FixVec, SaveData};
+use game_data::{
+    character::{Character, Guest},
+    dlc::pair::PairError,
+    lang::Filterable,
+};
+use recordkeeper::{character::PARTY_MAX, dlc::DLC4_PAIR_MAX, util::FixVec, SaveData};
 use ybc::{Button, Control, Field, Icon};
 use yew::prelude::*;
-use yew_feather::{Minus, Plus};
+use yew_feather::{Minus, Plus, X};
 
-use crate::{components::select::UpdateSelector, data::Data, lang::Text, save::SaveContext};
+use crate::{
+    components::select::{HtmlSelect, Options, SearchSelect, UpdateSelector},
+    data::Data,
+    dialog::{show_warning, DialogQueue},
+    lang::Text,
+    save::SaveContext,
+};
 
 #[derive(PartialEq, Properties)]
 pub struct PartyEditorProps<const N: usize, E>
@@ -105,6 +115,148 @@ where
     }
 }
 
+/// Editor for the NPCs that follow the party
+#[function_component]
+pub fn GuestEditor() -> Html {
+    let save_context = use_context::<SaveContext>().unwrap();
+    let data = use_context::<Data>().unwrap();
+    let lang = data.to_lang();
+
+    let guests = save_context
+        .get()
+        .get_save()
+        .party_guests
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+    let options = Options::from(data.game().characters.guests());
+
+    let add = {
+        let save_context = save_context.clone();
+        let options = options.clone();
+        Callback::from(move |index: usize| {
+            let id = options.get(index).id.try_into().unwrap();
+            save_context.try_edit(move |save| Ok(save.party_mut().add_guest(id)?))
+        })
+    };
+    let remove = |id: u16| {
+        let save_context = save_context.clone();
+        Callback::from(move |_: MouseEvent| {
+            save_context.try_edit(move |save| Ok(save.party_mut().remove_guest(id)?))
+        })
+    };
+
+    html! {
+        <Field>
+            <label class="label"><Text path="character_guests" /></label>
+
+            <Field classes={classes!("is-grouped", "is-grouped-multiline")}>
+                {for guests.into_iter().map(|id| {
+                    let name = data.game().characters.get_guest(id.into())
+                        .and_then(|guest| guest.get_filter(data.lang()))
+                        .map(|entry| Html::from(entry.text()))
+                        .unwrap_or_else(|| html!(<Text path="unnamed" args={vec![("id".into(), usize::from(id).into())]} />));
+                    html! {
+                        <Control>
+                            <Field classes={classes!("has-addons")}>
+                                <Control>
+                                    <span class="button is-static">{name}</span>
+                                </Control>
+                                <Control>
+                                    <Button onclick={remove(id)}>
+                                        <Icon><X /></Icon>
+                                    </Button>
+                                </Control>
+                            </Field>
+                        </Control>
+                    }
+                })}
+
+                <Control>
+                    <SearchSelect<Guest>
+                        current={None}
+                        options={options}
+                        on_select={add}
+                        lang={lang}
+                        placeholder={"+"}
+                    />
+                </Control>
+            </Field>
+        </Field>
+    }
+}
+
+/// Editor for the Unity Combo pairs (Future Redeemed)
+#[function_component]
+pub fn UnityPairEditor() -> Html {
+    let save_context = use_context::<SaveContext>().unwrap();
+    let dialog_context = use_context::<DialogQueue>().unwrap();
+    let data = use_context::<Data>().unwrap();
+
+    let game = data.game();
+    let current = save_context.get().get_save().dlc4_pairs();
+    let pairs = game.dlc.pairs.pairs();
+
+    let char_name = |id: usize| {
+        game.characters
+            .get_character(id)
+            .and_then(|c| c.get_filter(data.lang()))
+            .map(|entry| Html::from(entry.text()))
+            .unwrap_or_else(|| html!(<Text path="unnamed" args={vec![("id".into(), id.into())]} />))
+    };
+
+    html! {
+        <Field>
+            <label class="label"><Text path="character_dlc4_pairs" /></label>
+
+            <Field classes={classes!("is-grouped", "is-grouped-multiline")}>
+                {for (0..DLC4_PAIR_MAX).map(|i| {
+                    let current_id = current[i];
+                    let selected_idx = pairs
+                        .iter()
+                        .position(|p| p.id == current_id)
+                        .map(|idx| idx + 1)
+                        .unwrap_or_default();
+                    let update = {
+                        let save_context = save_context.clone();
+                        let dialog_context = dialog_context.clone();
+                        Callback::from(move |val: String| {
+                            let Ok(id) = val.parse::<u8>() else {
+                                return;
+                            };
+                            let dialog_context = dialog_context.clone();
+                            save_context.edit(move |save| {
+                                if let Err(e) = game.dlc.pairs.set_pair(save, i, id) {
+                                    let message = match e {
+                                        PairError::Unknown(id) => html!(<Text path="character_dlc4_pair_unknown" args={vec![("id".into(), id.into())]} />),
+                                        PairError::SharedCharacter(id) => html!(<Text path="character_dlc4_pair_shared" args={vec![("id".into(), id.into())]} />),
+                                    };
+                                    show_warning(&dialog_context, message);
+                                }
+                            })
+                        })
+                    };
+                    html! {
+                        <Control>
+                            <HtmlSelect value={current_id.to_string()} on_change={update} selected_idx={selected_idx}>
+                                <option value="0" selected={current_id == 0}><Text path="character_dlc4_pair_none" /></option>
+                                {for pairs.iter().map(|pair| {
+                                    let [a, b] = pair.characters;
+                                    html! {
+                                        <option value={pair.id.to_string()} selected={pair.id == current_id}>
+                                            {char_name(a)}{" & "}{char_name(b)}
+                                        </option>
+                                    }
+                                })}
+                            </HtmlSelect>
+                        </Control>
+                    }
+                })}
+            </Field>
+        </Field>
+    }
+}
+
 impl PartyVecEditor<PARTY_MAX> for SavePartyEditor {
     fn get<'s>(&self, save: &'s SaveData) -> &'s FixVec<u16, PARTY_MAX> {
         &save.party_characters
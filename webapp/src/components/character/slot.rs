@@ -38,6 +38,12 @@ pub struct AccessorySlotProps {
     pub slot_idx: usize,
 }
 
+#[derive(Properties, PartialEq, Clone, Copy)]
+pub struct ManualSlotProps {
+    pub char_id: usize,
+    pub class_id: usize,
+}
+
 #[derive(Clone)]
 struct Accessory {
     item: HtmlItem,
@@ -48,7 +54,7 @@ struct Accessory {
 pub fn SlotInput<E, I, N>(props: &SlotProps<E, I>) -> Html
 where
     N: PartialEq + 'static,
-    E: Editor<Target = Option<N>> + PartialEq,
+    E: Editor<Data = SaveData, Target = Option<N>> + PartialEq,
     I: PartialEq + 'static + Clone + HtmlName,
 {
     let save_context = use_context::<SaveContext>().unwrap();
@@ -169,6 +175,91 @@ pub fn AccessoryInput(props: &AccessorySlotProps) -> Html {
     }
 }
 
+/// Battle manual slot for a Future Redeemed character's class.
+#[function_component]
+pub fn ManualInput(props: &ManualSlotProps) -> Html {
+    let save_context = use_context::<SaveContext>().unwrap();
+    let save = save_context.get();
+
+    let data = use_context::<Data>().unwrap();
+    let lang = data.to_lang();
+
+    // Manual slot uses inventory slot index
+    let manual_type = data.game().items.battle_manual_type();
+    let inventory = match manual_type {
+        Some(manual_type) => save.get_save().inventory.slots(manual_type),
+        None => &[],
+    };
+    let manuals: Vec<_> = inventory
+        .iter()
+        .filter_map(|slot| {
+            slot.is_valid()
+                .then(|| slot.item_id())
+                .and_then(|id| data.game().items.get_item(manual_type?, id as u32))
+                .map(|&item| Accessory {
+                    item: HtmlItem(item),
+                    slot_index: slot.index(),
+                })
+        })
+        .collect();
+
+    let ManualSlotProps { char_id, class_id } = *props;
+    let current = save
+        .get_save()
+        .dlc4
+        .battle_manual(char_id, class_id)
+        .get()
+        .and_then(|manual| {
+            manuals
+                .iter()
+                .position(|opt| opt.slot_index == manual.inventory_slot_index())
+        });
+    let inventory: Options<_> = manuals.into_iter().collect();
+
+    let on_select = {
+        let save_context = save_context.clone();
+        let inventory = inventory.clone();
+        Callback::from(move |idx: usize| {
+            let slot_index = usize::from(inventory.get(idx).slot_index);
+            let Some(manual_type) = manual_type else {
+                return;
+            };
+            save_context.edit(move |save| {
+                let slot = save.inventory.slots(manual_type)[slot_index];
+                save.dlc4
+                    .battle_manual_mut(char_id, class_id)
+                    .set_from_inventory(&slot)
+            })
+        })
+    };
+
+    let clear_callback = {
+        let save_context = save_context.clone();
+        Callback::from(move |_: MouseEvent| {
+            save_context
+                .edit(move |save| save.dlc4.battle_manual_mut(char_id, class_id).set_empty())
+        })
+    };
+
+    html! {
+        <Field classes={classes!("has-addons")}>
+            <Control>
+                <SearchSelect<Accessory>
+                    current={current}
+                    options={inventory}
+                    on_select={on_select}
+                    lang={lang}
+                />
+            </Control>
+            <Control>
+                <Button onclick={clear_callback} disabled={current.is_none()}>
+                    <Icon><X /></Icon>
+                </Button>
+            </Control>
+        </Field>
+    }
+}
+
 impl AccessorySlotProps {
     fn save_slot(&self, save: &SaveData) -> Slot<ClassAccessory> {
         self.char.class_data(save).accessory_slot(self.slot_idx)
@@ -19,8 +19,9 @@ editor!(
     pub LevelEditor,
     u32,
     get |editor, save| save.characters[editor.char_idx].level,
-    set |editor, save, new_value| save.characters[editor.char_idx].level = new_value,
-    capture char_idx: usize
+    set |editor, save, new_value| save.characters[editor.char_idx].set_level(new_value, editor.level_exp),
+    assert |editor, value| { (1..=editor.level_exp.len() as u32).contains(value).then_some(()).ok_or_else(|| String::from("level out of bounds")) },
+    capture char_idx: usize, level_exp: &'static [u32]
 );
 
 #[rustfmt::skip]
@@ -37,8 +38,8 @@ editor!(
     ExpEditor,
     u32,
     get |editor, save| save.characters[editor.char_idx].exp,
-    set |editor, save, new_value| save.characters[editor.char_idx].exp = new_value,
-    capture char_idx: usize
+    set |editor, save, new_value| save.characters[editor.char_idx].set_exp(new_value, editor.level_exp),
+    capture char_idx: usize, level_exp: &'static [u32]
 );
 
 #[rustfmt::skip]
@@ -67,6 +68,12 @@ pub fn CharacterStats(props: &CharacterProps) -> Html {
     let save_context = use_context::<SaveContext>().unwrap();
     let selected_class_editor = SelectedClassEditor { char_idx };
     let selected_class = selected_class_editor.get(save_context.get().get_save()) as usize;
+    let level_exp = data.game().characters.level_exp();
+    let level_consistent = {
+        let character = &save_context.get().get_save().characters[char_idx];
+        // Uninitialized characters are ignored
+        character.level == 0 || character.is_level_consistent(level_exp)
+    };
 
     let update_selected_class = {
         let save_context = save_context.clone();
@@ -82,8 +89,11 @@ pub fn CharacterStats(props: &CharacterProps) -> Html {
                 <Field classes={classes!("mr-2")}>
                     <label class="label"><Text path="character_level" /></label>
                     <Control>
-                        <NumberInput<LevelEditor> editor={LevelEditor { char_idx }} />
+                        <NumberInput<LevelEditor> editor={LevelEditor { char_idx, level_exp }} />
                     </Control>
+                    {(!level_consistent).then(|| html! {
+                        <p class="help is-danger"><Text path="character_level_mismatch" /></p>
+                    })}
                 </Field>
                 <Field classes={classes!("mr-2")}>
                     <label class="label"><Text path="character_bexp" /></label>
@@ -102,7 +112,7 @@ pub fn CharacterStats(props: &CharacterProps) -> Html {
                 <Field classes={classes!("mr-2")}>
                     <label class="label"><Text path="character_exp" /></label>
                     <Control>
-                        <NumberInput<ExpEditor> editor={ExpEditor { char_idx }} />
+                        <NumberInput<ExpEditor> editor={ExpEditor { char_idx, level_exp }} />
                     </Control>
                 </Field>
                 <Field>
@@ -1,3 +1,4 @@
+use recordkeeper::SaveData;
 use ybc::Button;
 use yew::prelude::*;
 
@@ -15,7 +16,7 @@ where
 #[function_component]
 pub fn ColorList<E>(props: &ColorProps<E>) -> Html
 where
-    E: Editor<Target = usize> + PartialEq,
+    E: Editor<Data = SaveData, Target = usize> + PartialEq,
 {
     let save_context = use_context::<SaveContext>().unwrap();
 
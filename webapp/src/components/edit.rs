@@ -4,7 +4,7 @@ use std::{
 };
 
 use game_data::manual::Flag;
-use recordkeeper::{flags::FlagType, SaveData};
+use recordkeeper::{flags::FlagType, SaveData, SystemData};
 use strum::IntoEnumIterator;
 use wasm_bindgen::JsCast;
 use web_sys::{EventTarget, HtmlInputElement};
@@ -15,7 +15,7 @@ use yew_feather::X;
 use crate::{
     components::select::{HtmlSelect, SearchSelect},
     data::Data,
-    save::SaveContext,
+    save::{SaveContext, SaveManager},
     ToHtml,
 };
 
@@ -24,16 +24,20 @@ use crate::{
 ///
 /// To easily make new editors, use the [`editor!`] macro.
 pub trait Editor: Copy + 'static {
+    /// The file the editor operates on, either [`SaveData`] or
+    /// [`SystemData`].
+    type Data: EditorData;
+
     /// The value type to get and set.
     type Target;
 
     /// Gets the current value of the observed target from an instance
     /// of the save file.
-    fn get(&self, save: &SaveData) -> Self::Target;
+    fn get(&self, save: &Self::Data) -> Self::Target;
 
     /// Updates the value of the observed target for an instance of the
     /// save file.
-    fn set(&self, save: &mut SaveData, new: Self::Target);
+    fn set(&self, save: &mut Self::Data, new: Self::Target);
 
     /// Checks whether the given value is valid for the target.
     ///
@@ -43,6 +47,15 @@ pub trait Editor: Copy + 'static {
     }
 }
 
+/// A data file that editors can observe and edit.
+pub trait EditorData: 'static {
+    /// Gets the data file from the currently loaded file.
+    fn get(save: &SaveManager) -> &Self;
+
+    /// Submits an edit to the data file.
+    fn edit(save: &SaveContext, editor: impl FnOnce(&mut Self) + 'static);
+}
+
 #[rustfmt::skip]
 editor!(
     pub FlagEditor,
@@ -67,6 +80,7 @@ macro_rules! editor {
         }
 
         impl $crate::components::edit::Editor for $name {
+            type Data = recordkeeper::SaveData;
             type Target = $value;
 
             fn get(&self, save: &recordkeeper::SaveData) -> Self::Target {
@@ -180,7 +194,7 @@ where
     let save_context = use_context::<SaveContext>().unwrap();
     let current_value = {
         let save = save_context.get();
-        props.editor.get(save.get_save())
+        props.editor.get(E::Data::get(&save))
     };
     let current_index = <E as Editor>::Target::iter()
         .position(|e| e == current_value)
@@ -190,7 +204,7 @@ where
     let callback = Callback::from(move |val: String| {
         let value = val.parse::<usize>().unwrap();
         let value = <E as Editor>::Target::iter().nth(value).unwrap();
-        save_context.edit(move |save| editor.set(save, value));
+        E::Data::edit(&save_context, move |save| editor.set(save, value));
     });
 
     html! {
@@ -208,11 +222,13 @@ pub fn CheckboxInput<E: Editor<Target = bool> + PartialEq>(props: &CheckboxInput
     let save_context = use_context::<SaveContext>().unwrap();
     let checked = {
         let save = save_context.get();
-        props.editor.get(save.get_save())
+        props.editor.get(E::Data::get(&save))
     };
 
     let editor = props.editor;
-    let update = Callback::from(move |_| save_context.edit(move |save| editor.set(save, !checked)));
+    let update = Callback::from(move |_| {
+        E::Data::edit(&save_context, move |save| editor.set(save, !checked))
+    });
 
     html! {
         <Checkbox name="ngp" checked={checked} update={update}>
@@ -231,7 +247,7 @@ where
     let save_context = use_context::<SaveContext>().unwrap();
     let current_value = {
         let save = save_context.get();
-        props.editor.get(save.get_save())
+        props.editor.get(E::Data::get(&save))
     };
 
     let input = use_state(|| String::new());
@@ -269,7 +285,7 @@ where
                         None => Some(v),
                     }) {
                     Some(v) => {
-                        save_context.edit(move |save| editor.set(save, v));
+                        E::Data::edit(&save_context, move |save| editor.set(save, v));
                         valid_state.set(true);
                     }
                     None => {
@@ -328,7 +344,7 @@ where
     // Conveniently, this is None when the value is 0
     let current = props
         .editor
-        .get(save.get_save())
+        .get(E::Data::get(&save))
         .try_into()
         .unwrap()
         .checked_sub(1);
@@ -337,8 +353,9 @@ where
         let editor = props.editor;
         let save_context = save_context.clone();
         Callback::from(move |new: usize| {
-            save_context
-                .edit(move |save| editor.set(save, new.checked_add(1).unwrap().try_into().unwrap()))
+            E::Data::edit(&save_context, move |save| {
+                editor.set(save, new.checked_add(1).unwrap().try_into().unwrap())
+            })
         })
     };
 
@@ -346,7 +363,9 @@ where
         let editor = props.editor;
         let save_context = save_context.clone();
         Callback::from(move |_: MouseEvent| {
-            save_context.edit(move |save| editor.set(save, 0usize.try_into().unwrap()))
+            E::Data::edit(&save_context, move |save| {
+                editor.set(save, 0usize.try_into().unwrap())
+            })
         })
     };
 
@@ -388,17 +407,38 @@ impl<E: Editor> Editor for ToBool<E>
 where
     E::Target: FlagConvert,
 {
+    type Data = E::Data;
     type Target = bool;
 
-    fn get(&self, save: &SaveData) -> Self::Target {
+    fn get(&self, save: &Self::Data) -> Self::Target {
         self.0.get(save).to_bool()
     }
 
-    fn set(&self, save: &mut SaveData, new: Self::Target) {
+    fn set(&self, save: &mut Self::Data, new: Self::Target) {
         self.0.set(save, E::Target::from_bool(new))
     }
 }
 
+impl EditorData for SaveData {
+    fn get(save: &SaveManager) -> &Self {
+        save.get_save()
+    }
+
+    fn edit(save: &SaveContext, editor: impl FnOnce(&mut Self) + 'static) {
+        save.edit(editor)
+    }
+}
+
+impl EditorData for SystemData {
+    fn get(save: &SaveManager) -> &Self {
+        save.get_system()
+    }
+
+    fn edit(save: &SaveContext, editor: impl FnOnce(&mut Self) + 'static) {
+        save.edit_system(editor)
+    }
+}
+
 impl FlagConvert for u32 {
     fn from_bool(b: bool) -> Self {
         u8::from(b).into()
@@ -226,6 +226,7 @@ impl LocationVisitEditor {
 }
 
 impl Editor for LocationVisitEditor {
+    type Data = SaveData;
     type Target = bool;
 
     fn get(&self, save: &SaveData) -> Self::Target {
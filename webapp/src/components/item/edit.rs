@@ -8,7 +8,7 @@ use yew_feather::{Tool, X};
 use crate::{
     components::{
         dlc::masha::MashaModal,
-        edit::{editor, NumberInput},
+        edit::{editor, CheckboxInput, NumberInput},
         item::HtmlItem,
         select::{Options, SearchSelect},
     },
@@ -28,6 +28,30 @@ editor!(
     capture item_type: ItemType, index: usize
 );
 
+editor!(
+    pub FavoriteEditor,
+    bool,
+    get |editor, save| {
+        save.inventory.slots(editor.item_type)[editor.index].is_favorite()
+    },
+    set |editor, save, new_value| {
+        ItemEditor::new(save, editor.item_type, editor.index).set_favorite(new_value)
+    },
+    capture item_type: ItemType, index: usize
+);
+
+editor!(
+    pub NewEditor,
+    bool,
+    get |editor, save| {
+        save.inventory.slots(editor.item_type)[editor.index].is_new()
+    },
+    set |editor, save, new_value| {
+        ItemEditor::new(save, editor.item_type, editor.index).set_new(new_value)
+    },
+    capture item_type: ItemType, index: usize
+);
+
 #[derive(Properties, PartialEq, Clone)]
 pub struct ItemEditorProps {
     pub index: usize,
@@ -108,6 +132,16 @@ pub fn ItemRow(props: &ItemEditorProps) -> Html {
                 <td>
                     <NumberInput<AmountEditor> editor={amount_editor} />
                 </td>
+                <td>
+                    if slot.is_valid() {
+                        <CheckboxInput<FavoriteEditor> editor={FavoriteEditor { index, item_type }} />
+                    }
+                </td>
+                <td>
+                    if slot.is_valid() {
+                        <CheckboxInput<NewEditor> editor={NewEditor { index, item_type }} />
+                    }
+                </td>
                 <td>
                     <Field classes={classes!("has-addons")}>
                         {if slot.is_crafted_accessory() {
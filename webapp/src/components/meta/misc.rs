@@ -65,6 +65,7 @@ pub fn Settings() -> Html {
 }
 
 impl Editor for DifficultyEditor {
+    type Data = recordkeeper::SaveData;
     type Target = Difficulty;
 
     fn get(&self, save: &recordkeeper::SaveData) -> Self::Target {
@@ -13,3 +13,4 @@ pub mod item;
 pub mod meta;
 pub mod ouroboros;
 pub mod quest;
+pub mod system;
@@ -1,11 +1,9 @@
 use game_data::{
     character::{Art, Skill},
-    ouroboros::Ouroboros,
-    GameData,
+    ouroboros::InterlinkFlags,
 };
 use recordkeeper::{
     character::{OUROBOROS_ART_MAX, OUROBOROS_SKILL_MAX},
-    flags::FlagType,
     SaveData,
 };
 use ybc::{Control, Field, Notification, Tile};
@@ -17,7 +15,7 @@ use crate::{
             class::{art_to_id, skill_to_id},
             slot::SlotInput,
         },
-        edit::{CheckboxInput, FlagEditor, NumberInput, ToBool},
+        edit::{CheckboxInput, NumberInput},
         ouroboros::tree::OuroTree,
     },
     data::Data,
@@ -34,6 +32,24 @@ use super::{
 
 mod tree;
 
+#[rustfmt::skip]
+editor!(
+    InterlinkEnabled,
+    bool,
+    get |editor, save| editor.flags.state(save).enabled,
+    set |editor, save, new| editor.flags.set_enabled(save, new),
+    capture flags: InterlinkFlags
+);
+
+#[rustfmt::skip]
+editor!(
+    InterlinkSharedSlot,
+    bool,
+    get |editor, save| editor.flags.state(save).shared_slot,
+    set |editor, save, new| editor.flags.set_shared_slot(save, new),
+    capture flags: InterlinkFlags
+);
+
 #[rustfmt::skip]
 editor!(
     SpEditor,
@@ -103,10 +119,7 @@ pub fn OuroborosEditor(props: &OuroborosProps) -> Html {
     let skill_mapper = Callback::from(skill_to_id);
 
     let char_idx = props.char_id.checked_sub(1).unwrap();
-    let share_slot_flag = ToBool(FlagEditor {
-        flag_type: FlagType::Bit,
-        flag_index: ouroboros.share_slot_flag,
-    });
+    let interlink = ouroboros.interlink_flags(&data.game().manual.flags);
 
     html! {
         <>
@@ -116,14 +129,14 @@ pub fn OuroborosEditor(props: &OuroborosProps) -> Html {
                         <Tile classes={classes!("is-align-items-center")}>
                             <Field classes={classes!("mr-2", "is-grouped", "is-grouped-multiline")}>
                                 <Control>
-                                    <CheckboxInput<ToBool<FlagEditor>> editor={ToBool(get_enable_flag(data.game(), ouroboros))}>
+                                    <CheckboxInput<InterlinkEnabled> editor={InterlinkEnabled { flags: interlink }}>
                                         {" "}<Text path="ouroboros_enable" />
-                                    </CheckboxInput<ToBool<FlagEditor>>>
+                                    </CheckboxInput<InterlinkEnabled>>
                                 </Control>
                                 <Control>
-                                    <CheckboxInput<ToBool<FlagEditor>> editor={share_slot_flag}>
+                                    <CheckboxInput<InterlinkSharedSlot> editor={InterlinkSharedSlot { flags: interlink }}>
                                         {" "}<Text path="ouroboros_share_slot" />
-                                    </CheckboxInput<ToBool<FlagEditor>>>
+                                    </CheckboxInput<InterlinkSharedSlot>>
                                 </Control>
                             </Field>
                         </Tile>
@@ -176,18 +189,8 @@ pub fn OuroborosEditor(props: &OuroborosProps) -> Html {
     }
 }
 
-fn get_enable_flag(data: &GameData, ouroboros: &Ouroboros) -> FlagEditor {
-    if ouroboros.id == 1 {
-        return data.manual.flags.ouro_enable_noah.into();
-    }
-    let flag = data.manual.flags.ouro_enable;
-    return FlagEditor {
-        flag_type: FlagType::from_bits(flag.bits),
-        flag_index: flag.index + ouroboros.id - 2,
-    };
-}
-
 impl Editor for ArtEditor {
+    type Data = SaveData;
     type Target = Option<u16>;
 
     fn get(&self, save: &SaveData) -> Self::Target {
@@ -206,6 +209,7 @@ impl Editor for ArtEditor {
 }
 
 impl Editor for SkillEditor {
+    type Data = SaveData;
     type Target = Option<u16>;
 
     fn get(&self, save: &SaveData) -> Self::Target {
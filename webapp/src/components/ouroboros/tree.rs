@@ -1,11 +1,12 @@
-use game_data::ouroboros::{OuroTreeNode, Ouroboros};
-use ybc::{Control, Field};
+use game_data::ouroboros::{OuroNodeKind, OuroTreeError, OuroTreeNode, Ouroboros};
+use ybc::{Button, Checkbox, Control, Field};
 use yew::prelude::*;
 
 use crate::{
-    components::edit::{editor, CheckboxInput},
     data::Data,
+    dialog::{show_warning, DialogQueue},
     lang::Text,
+    save::SaveContext,
 };
 
 #[derive(Properties, PartialEq)]
@@ -13,41 +14,123 @@ pub struct OuroTreeProps {
     pub ouroboros: &'static Ouroboros,
 }
 
-editor!(
-    SoulEditor,
-    bool,
-    get |editor, save| save.ouroboros[editor.char_idx].skill_tree.get(editor.index),
-    set |editor, save, new| save.ouroboros[editor.char_idx].skill_tree.set(editor.index, new),
-    capture char_idx: usize, index: usize
-);
+#[derive(Properties, PartialEq)]
+struct SoulNodeProps {
+    ouroboros: &'static Ouroboros,
+    node_id: usize,
+}
 
 #[function_component]
 pub fn OuroTree(props: &OuroTreeProps) -> Html {
-    let data = use_context::<Data>().unwrap();
-    let char_idx = props.ouroboros.id.checked_sub(1).unwrap();
+    let save_context = use_context::<SaveContext>().unwrap();
+    let dialog_context = use_context::<DialogQueue>().unwrap();
+    let ouroboros = props.ouroboros;
+    let char_idx = ouroboros.id.checked_sub(1).unwrap();
+
+    let issues = ouroboros
+        .check_tree(&save_context.get().get_save().ouroboros[char_idx])
+        .len();
+
+    let unlock_all = Callback::from(move |_: MouseEvent| {
+        let dialog_context = dialog_context.clone();
+        save_context.edit(move |save| {
+            if let Err(e) = ouroboros.unlock_all(&mut save.ouroboros[char_idx]) {
+                show_error(&dialog_context, e);
+            }
+        })
+    });
 
     html! {
-        <Field classes={classes!("is-grouped", "is-grouped-multiline")}>
-            {for props.ouroboros.tree_nodes().map(|(index, node)| html! {
+        <>
+            <Field classes={classes!("is-grouped", "is-grouped-multiline")}>
+                {for ouroboros.tree_nodes().map(|(node_id, _)| html! {
+                    <Control>
+                        <SoulNode ouroboros={ouroboros} node_id={node_id} />
+                    </Control>
+                })}
+            </Field>
+            <Field classes={classes!("is-grouped", "is-align-items-center")}>
                 <Control>
-                    <CheckboxInput<SoulEditor> editor={SoulEditor { char_idx, index: index.checked_sub(1).unwrap() }}>
-                        {" "}
-                        <b>{soul_lang(&node)}</b>
-                        {": "}
-                        {node.get_param_name(data.game(), data.lang())}
-                    </CheckboxInput<SoulEditor>>
+                    <Button onclick={unlock_all}><Text path="ouroboros_tree_unlock_all" /></Button>
                 </Control>
-            })}
-        </Field>
+                if issues > 0 {
+                    <Control>
+                        <span class="has-text-warning-dark">
+                            <Text path="ouroboros_tree_issues" args={vec![("count".into(), issues.into())]} />
+                        </span>
+                    </Control>
+                }
+            </Field>
+        </>
     }
 }
 
+/// Checkbox for a skill tree node. Unlocking spends SP, locking gives it back.
+#[function_component]
+fn SoulNode(props: &SoulNodeProps) -> Html {
+    let save_context = use_context::<SaveContext>().unwrap();
+    let dialog_context = use_context::<DialogQueue>().unwrap();
+    let data = use_context::<Data>().unwrap();
+
+    let ouroboros = props.ouroboros;
+    let node_id = props.node_id;
+    let node = ouroboros.tree_node(node_id).unwrap();
+    let char_idx = ouroboros.id.checked_sub(1).unwrap();
+    let checked = save_context.get().get_save().ouroboros[char_idx]
+        .skill_tree
+        .get(node_id - 1);
+
+    let update = Callback::from(move |_| {
+        let dialog_context = dialog_context.clone();
+        save_context.edit(move |save| {
+            let save_ouro = &mut save.ouroboros[char_idx];
+            let result = if checked {
+                ouroboros.refund_node(save_ouro, node_id)
+            } else {
+                ouroboros.unlock_node(save_ouro, node_id)
+            };
+            if let Err(e) = result {
+                show_error(&dialog_context, e);
+            }
+        })
+    });
+
+    html! {
+        <Checkbox name="soul" checked={checked} update={update}>
+            {" "}
+            <b>{soul_lang(node)}</b>
+            {": "}
+            {node.get_param_name(data.game(), data.lang())}
+            {" "}
+            <Text path="ouroboros_tree_cost" args={vec![("sp".into(), node.sp_cost.into())]} />
+        </Checkbox>
+    }
+}
+
+fn show_error(dialog_context: &DialogQueue, error: OuroTreeError) {
+    let message = match error {
+        OuroTreeError::NotEnoughSp { needed, available } => html! {
+            <Text path="ouroboros_tree_sp_error" args={vec![
+                ("needed".into(), needed.into()),
+                ("available".into(), available.into()),
+            ]} />
+        },
+        OuroTreeError::UnknownNode(id)
+        | OuroTreeError::MissingPrerequisite {
+            prerequisite: id, ..
+        } => html! {
+            <Text path="ouroboros_tree_node_error" args={vec![("id".into(), id.into())]} />
+        },
+    };
+    show_warning(dialog_context, message)
+}
+
 fn soul_lang(node: &OuroTreeNode) -> Html {
-    let path = match node {
-        OuroTreeNode::UnlockArt(_) => "art_unlock",
-        OuroTreeNode::UnlockSkill(_) => "skill_unlock",
-        OuroTreeNode::UpgradeArt(_) => "art_upgrade",
-        OuroTreeNode::UpgradeSkill(_) => "skill_upgrade",
+    let path = match node.kind {
+        OuroNodeKind::UnlockArt(_) => "art_unlock",
+        OuroNodeKind::UnlockSkill(_) => "skill_unlock",
+        OuroNodeKind::UpgradeArt(_) => "art_upgrade",
+        OuroNodeKind::UpgradeSkill(_) => "skill_upgrade",
     };
     html!(<Text path={format!("ouroboros_tree_{path}")} />)
 }
@@ -72,6 +72,7 @@ pub fn QuestRow(props: &QuestEditorProps) -> Html {
 }
 
 impl Editor for StatusEditor {
+    type Data = recordkeeper::SaveData;
     type Target = QuestStatus;
 
     fn get(&self, save: &recordkeeper::SaveData) -> Self::Target {
@@ -1,4 +1,5 @@
 use game_data::lang::{Filterable, Id};
+use recordkeeper::SaveData;
 use yew::prelude::*;
 
 use crate::{
@@ -75,7 +76,7 @@ pub fn UpdateSelector<F: Filterable + PartialEq + Id + 'static>(
 #[function_component]
 pub fn EditorSelector<E, F>(props: &EditorSelectorProps<E, F>) -> Html
 where
-    E: PartialEq + Editor<Target = usize>,
+    E: PartialEq + Editor<Data = SaveData, Target = usize>,
     F: Filterable + PartialEq + Id + 'static,
 {
     let save_context = use_context::<SaveContext>().unwrap();
@@ -2,7 +2,7 @@ use ybc::{Button, Icon, Menu, MenuList, Size};
 use yew::prelude::*;
 use yew_feather::{
     BookOpen, Clock, Crosshair, Download, FilePlus, Flag, HelpCircle, Info, LifeBuoy, Map,
-    ShoppingBag, Target, TrendingUp, Triangle, Users, Watch,
+    Settings, ShoppingBag, Smile, Target, TrendingUp, Triangle, Users, Video, Watch,
 };
 use yew_router::prelude::{use_route, Link};
 
@@ -69,6 +69,7 @@ fn SaveSidebar() -> Html {
         Tab("base_field", html!(<Map />), Route::Field),
         Tab("base_quests", html!(<HelpCircle />), Route::Quests),
         Tab("base_ums", html!(<Crosshair />), Route::Uniques),
+        Tab("base_collepedia", html!(<BookOpen />), Route::Collepedia),
         Tab("base_chrono", html!(<Clock />), Route::ChronoData),
         // Party formations if not DLC4
     ];
@@ -139,7 +140,28 @@ fn SaveSidebar() -> Html {
 
 #[function_component]
 pub fn SystemSidebar() -> Html {
-    html! {}
+    let route = use_route::<Route>();
+
+    let menu = [
+        MenuItem::Category(Category("meta")),
+        MenuItem::Tabs(vec![Tab("system_meta", html!(<Info />), Route::SystemMeta)]),
+        MenuItem::Category(Category("system")),
+        MenuItem::Tabs(vec![
+            Tab(
+                "system_settings",
+                html!(<Settings />),
+                Route::SystemSettings,
+            ),
+            Tab("system_costumes", html!(<Smile />), Route::Costumes),
+            Tab("system_theater", html!(<Video />), Route::EventTheater),
+        ]),
+    ];
+
+    html! {
+        <>
+            {menu.into_iter().map(|i| i.into_html(route)).collect::<Html>()}
+        </>
+    }
 }
 
 #[function_component]
@@ -0,0 +1,121 @@
+use game_data::character::Costume;
+use recordkeeper::{SystemData, SystemFlag, SETTINGS_MAX};
+
+use crate::components::edit::Editor;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct EventEditor(pub usize);
+
+/// Editor for a global setting.
+///
+/// Use [`SettingEditor::new`] to check that the option fits in the
+/// system file.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SettingEditor(usize);
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct FlagEditor(pub SystemFlag);
+
+/// Editor for a character's costume unlock status.
+///
+/// Costumes that can't be stored in the system file are never
+/// updated, see [`Costume::is_unlocked`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct CostumeEditor {
+    pub costume: Costume,
+    pub char_id: usize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum CounterEditor {
+    Save(usize),
+    Load(usize),
+    Continue,
+    SettingsSave,
+}
+
+impl SettingEditor {
+    /// Returns an editor for the given option, or `None` if the option ID is
+    /// out of bounds for the system file (`>= SETTINGS_MAX`).
+    pub fn new(option_id: usize) -> Option<Self> {
+        (option_id < SETTINGS_MAX).then_some(Self(option_id))
+    }
+}
+
+impl Editor for EventEditor {
+    type Data = SystemData;
+    type Target = bool;
+
+    fn get(&self, system: &SystemData) -> Self::Target {
+        system.is_event_unlocked(self.0)
+    }
+
+    fn set(&self, system: &mut SystemData, new: Self::Target) {
+        system.set_event_unlocked(self.0, new)
+    }
+}
+
+impl Editor for SettingEditor {
+    type Data = SystemData;
+    type Target = u16;
+
+    fn get(&self, system: &SystemData) -> Self::Target {
+        system.get_setting(self.0)
+    }
+
+    fn set(&self, system: &mut SystemData, new: Self::Target) {
+        system.set_setting(self.0, new)
+    }
+}
+
+impl Editor for FlagEditor {
+    type Data = SystemData;
+    type Target = bool;
+
+    fn get(&self, system: &SystemData) -> Self::Target {
+        system.is_flag_set(self.0)
+    }
+
+    fn set(&self, system: &mut SystemData, new: Self::Target) {
+        system.set_flag(self.0, new)
+    }
+}
+
+impl Editor for CostumeEditor {
+    type Data = SystemData;
+    type Target = bool;
+
+    fn get(&self, system: &SystemData) -> Self::Target {
+        self.costume
+            .is_unlocked(self.char_id, system)
+            .unwrap_or_default()
+    }
+
+    fn set(&self, system: &mut SystemData, new: Self::Target) {
+        // Out of bounds costumes are left as they are
+        self.costume.set_unlocked(self.char_id, system, new).ok();
+    }
+}
+
+impl Editor for CounterEditor {
+    type Data = SystemData;
+    type Target = u64;
+
+    fn get(&self, system: &SystemData) -> Self::Target {
+        match *self {
+            Self::Save(slot) => system.save_count(slot),
+            Self::Load(slot) => system.load_count(slot),
+            Self::Continue => system.continue_counter,
+            Self::SettingsSave => system.settings_save_counter,
+        }
+    }
+
+    fn set(&self, system: &mut SystemData, new: Self::Target) {
+        match *self {
+            Self::Save(slot) => system.set_save_count(slot, new),
+            Self::Load(slot) => system.set_load_count(slot, new),
+            Self::Continue => system.continue_counter = new,
+            Self::SettingsSave => system.settings_save_counter = new,
+        }
+    }
+}
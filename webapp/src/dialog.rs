@@ -147,6 +147,18 @@ impl DialogLayout {
     }
 }
 
+/// Shows a warning dialog with an OK button.
+pub fn show_warning(dialog_context: &DialogQueue, message: Html) {
+    dialog_context.dispatch(Some(
+        DialogLayout::Ok {
+            title: None,
+            message,
+            severity: Severity::Warning,
+        }
+        .into(),
+    ))
+}
+
 impl Severity {
     fn title(self) -> &'static str {
         match self {
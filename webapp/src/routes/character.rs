@@ -6,19 +6,22 @@ use yew::prelude::*;
 use crate::{
     components::{
         character::{
-            party::{PartyEditor, SavePartyEditor},
+            party::{GuestEditor, PartyEditor, SavePartyEditor, UnityPairEditor},
             CharacterEditor,
         },
         select::Selector,
     },
     data::Data,
     lang::Text,
+    save::SaveContext,
 };
 
 #[function_component]
 pub fn Characters() -> Html {
     let char_id = use_state(|| 1);
     let data = use_context::<Data>().unwrap();
+    let save_context = use_context::<SaveContext>().unwrap();
+    let is_dlc4 = save_context.get().get_save().is_dlc4();
 
     html! {
         <Container>
@@ -33,6 +36,10 @@ pub fn Characters() -> Html {
                 </Tile>
                 <Tile classes={classes!("is-10", "is-justify-content-right")}>
                     <PartyEditor<PARTY_MAX, SavePartyEditor> editor={SavePartyEditor} />
+                    <GuestEditor />
+                    if is_dlc4 {
+                        <UnityPairEditor />
+                    }
                 </Tile>
             </Tile>
             <div>
@@ -0,0 +1,227 @@
+use game_data::{
+    collepedia::{CollepediaEntry, CollepediaError, CollepediaRegistry, CollepediaReward},
+    lang::Nameable,
+};
+use recordkeeper::item::ItemType;
+use ybc::{Button, Checkbox, Container, Control, Field, Table, Title};
+use yew::prelude::*;
+
+use crate::{
+    components::{
+        edit::{editor, CheckboxInput},
+        select::HtmlSelect,
+    },
+    data::Data,
+    dialog::{show_warning, DialogQueue},
+    lang::Text,
+    save::SaveContext,
+};
+
+#[derive(Properties, PartialEq)]
+struct EntryProps {
+    entry: &'static CollepediaEntry,
+}
+
+#[derive(Properties, PartialEq)]
+struct RewardProps {
+    reward: &'static CollepediaReward,
+}
+
+#[derive(Properties, PartialEq)]
+struct ItemNameProps {
+    item_type: ItemType,
+    id: u32,
+}
+
+#[rustfmt::skip]
+editor!(
+    TurnedInEditor,
+    bool,
+    get |editor, save| editor.entry.is_turned_in(save),
+    set |editor, save, new| editor.registry.set_turned_in(save, editor.entry.id, new).unwrap(),
+    capture registry: &'static CollepediaRegistry, entry: &'static CollepediaEntry
+);
+
+#[function_component]
+pub fn CollepediaPage() -> Html {
+    let save_context = use_context::<SaveContext>().unwrap();
+    let data = use_context::<Data>().unwrap();
+
+    let registry = &data.game().collepedia;
+    let regions = registry.regions();
+    let region = use_state(|| regions.first().copied().unwrap_or_default());
+
+    let current = *region;
+    let selected_idx = regions
+        .iter()
+        .position(|&r| r == current)
+        .unwrap_or_default();
+    let progress = registry.region_progress(save_context.get().get_save(), current);
+
+    let region_state = region.clone();
+    let on_change = Callback::from(move |val: String| region_state.set(val.parse().unwrap()));
+
+    let complete_region = Callback::from(move |_: MouseEvent| {
+        save_context.edit(move |save| registry.complete_region(save, current))
+    });
+
+    html! {
+        <Container>
+            <Field classes={classes!("is-grouped", "is-align-items-center")}>
+                <Control>
+                    <HtmlSelect value={current.to_string()} on_change={on_change} selected_idx={selected_idx}>
+                        {for regions.iter().map(|&id| html! {
+                            <option value={id.to_string()} selected={id == current}>{region_name(&data, id)}</option>
+                        })}
+                    </HtmlSelect>
+                </Control>
+                <Control>
+                    <Button onclick={complete_region}><Text path="collepedia_complete" /></Button>
+                </Control>
+                <Control>
+                    <Text path="collepedia_progress" args={vec![
+                        ("turned_in".into(), progress.turned_in.into()),
+                        ("total".into(), progress.total.into()),
+                    ]} />
+                </Control>
+            </Field>
+
+            <Table classes={classes!("is-fullwidth")}>
+                <thead>
+                    <tr>
+                        <th><Text path="collepedia_id" /></th>
+                        <th><Text path="collepedia_entry" /></th>
+                        <th><Text path="collepedia_item" /></th>
+                        <th><Text path="collepedia_turned_in" /></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for registry.region_entries(current).map(|entry| html!(<EntryRow entry={entry} />))}
+                </tbody>
+            </Table>
+
+            <Title><Text path="collepedia_rewards" /></Title>
+            <Table classes={classes!("is-fullwidth")}>
+                <thead>
+                    <tr>
+                        <th><Text path="collepedia_required" /></th>
+                        <th><Text path="collepedia_reward" /></th>
+                        <th><Text path="collepedia_claimed" /></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for registry.region_rewards(current).map(|reward| html!(<RewardRow reward={reward} />))}
+                </tbody>
+            </Table>
+        </Container>
+    }
+}
+
+#[function_component]
+fn EntryRow(props: &EntryProps) -> Html {
+    let data = use_context::<Data>().unwrap();
+    let entry = props.entry;
+    let editor = TurnedInEditor {
+        registry: &data.game().collepedia,
+        entry,
+    };
+
+    html! {
+        <tr>
+            <th>{entry.id}</th>
+            <td><ItemName item_type={ItemType::Collectopedia} id={entry.id} /></td>
+            <td>
+                <ItemName item_type={ItemType::Collection} id={entry.item_id} />
+                {format!(" x{}", entry.amount)}
+            </td>
+            <td><CheckboxInput<TurnedInEditor> editor={editor} /></td>
+        </tr>
+    }
+}
+
+/// Checkbox for a region reward. Rewards can only be claimed once enough
+/// entries have been turned in.
+#[function_component]
+fn RewardRow(props: &RewardProps) -> Html {
+    let save_context = use_context::<SaveContext>().unwrap();
+    let dialog_context = use_context::<DialogQueue>().unwrap();
+    let data = use_context::<Data>().unwrap();
+
+    let registry = &data.game().collepedia;
+    let reward = props.reward;
+    let claimed = reward.is_claimed(save_context.get().get_save());
+
+    let update = Callback::from(move |_| {
+        let dialog_context = dialog_context.clone();
+        save_context.edit(move |save| {
+            if let Err(e) = registry.set_claimed(save, reward.id, !claimed) {
+                show_error(&dialog_context, e);
+            }
+        })
+    });
+
+    html! {
+        <tr>
+            <td>{reward.required}</td>
+            <td>
+                {match reward.item_id {
+                    0 => html!(),
+                    id => html! {
+                        <>
+                            {match u16::try_from(id).ok().and_then(ItemType::get_by_item_id) {
+                                Some(item_type) => html!(<ItemName item_type={item_type} id={id} />),
+                                None => html!(<Text path="unnamed" args={vec![("id".into(), id.into())]} />),
+                            }}
+                            {format!(" x{}", reward.amount)}
+                        </>
+                    },
+                }}
+            </td>
+            <td><Checkbox name="claimed" checked={claimed} update={update} /></td>
+        </tr>
+    }
+}
+
+#[function_component]
+fn ItemName(props: &ItemNameProps) -> Html {
+    let data = use_context::<Data>().unwrap();
+
+    data.game()
+        .items
+        .get_item(props.item_type, props.id)
+        .and_then(|item| item.get_name_str(data.lang()))
+        .map(Html::from)
+        .unwrap_or_else(
+            || html!(<Text path="unnamed" args={vec![("id".into(), props.id.into())]} />),
+        )
+}
+
+fn region_name(data: &Data, id: usize) -> Html {
+    data.game()
+        .field
+        .get_map_by_id(id)
+        .and_then(|map| map.get_name_str(data.lang()))
+        .map(Html::from)
+        .unwrap_or_else(|| html!(<Text path="unnamed" args={vec![("id".into(), id.into())]} />))
+}
+
+fn show_error(dialog_context: &DialogQueue, error: CollepediaError) {
+    let message = match error {
+        CollepediaError::NotEnoughEntries {
+            required,
+            turned_in,
+        } => html! {
+            <Text path="collepedia_reward_error" args={vec![
+                ("required".into(), required.into()),
+                ("turned_in".into(), turned_in.into()),
+            ]} />
+        },
+        CollepediaError::UnknownEntry(id) => html! {
+            <Text path="collepedia_unknown" args={vec![("id".into(), id.into())]} />
+        },
+        CollepediaError::UnknownReward(id) => html! {
+            <Text path="collepedia_unknown" args={vec![("id".into(), id.into())]} />
+        },
+    };
+    show_warning(dialog_context, message)
+}
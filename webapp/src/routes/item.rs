@@ -3,9 +3,10 @@ use crate::components::item::HtmlItem;
 use crate::components::page::{PageControls, PageOrganizer};
 use crate::components::select::{Options, SearchSelect};
 use crate::data::Data;
+use crate::dialog::{DialogLayout, DialogQueue};
 use crate::lang::{Lang, Text};
 use crate::save::SaveContext;
-use game_data::item::Item;
+use game_data::item::{GiveItemsFilter, Item};
 use recordkeeper::item::{Inventory, ItemSlot, ItemType};
 use ybc::{Button, Buttons, Container, Control, Field, Table, Tile};
 use yew::prelude::*;
@@ -41,6 +42,11 @@ struct PageChangeProps {
     pub page_state: UseStateHandle<usize>,
 }
 
+#[derive(Properties, PartialEq)]
+struct ItemTypeProps {
+    pub item_type: ItemType,
+}
+
 #[derive(Properties, PartialEq)]
 struct FirstEmptyProps {
     pub item_type: ItemType,
@@ -99,6 +105,12 @@ pub fn ItemInventory() -> Html {
                     <Control>
                         <FirstEmptySlot item_type={*item_type} page_state={page.clone()} />
                     </Control>
+                    <Control>
+                        <GiveAllItems item_type={*item_type} />
+                    </Control>
+                    <Control>
+                        <MarkAllSeen item_type={*item_type} />
+                    </Control>
                 </Tile>
             </Tile>
 
@@ -126,6 +138,8 @@ fn TablePage(props: &TableProps) -> Html {
                     <th><Text path="item_slot_index" /></th>
                     <th><Text path="item_item" /></th>
                     <th><Text path="item_amount" /></th>
+                    <th><Text path="item_favorite" /></th>
+                    <th><Text path="item_new" /></th>
                     <th><Text path="item_actions" /></th>
                 </tr>
             </thead>
@@ -192,6 +206,74 @@ fn FirstEmptySlot(props: &FirstEmptyProps) -> Html {
     }
 }
 
+/// Adds all items of the type at max amount, after confirming the changes.
+#[function_component]
+fn GiveAllItems(props: &ItemTypeProps) -> Html {
+    let item_type = props.item_type;
+    let save = use_context::<SaveContext>().unwrap();
+    let data = use_context::<Data>().unwrap();
+    let dialog = use_context::<DialogQueue>().unwrap();
+    let items = &data.game().items;
+
+    let on_click = Callback::from(move |_: MouseEvent| {
+        let filter = GiveItemsFilter::all([item_type]);
+        let plan = items.plan_give_items(&save.get().get_save().inventory, &filter);
+        let added = plan
+            .grants
+            .iter()
+            .filter(|g| g.previous_amount == 0)
+            .count();
+        let refilled = plan.grants.len() - added;
+        let no_space = plan.no_space.len();
+
+        let save = save.clone();
+        let yes_callback = Callback::from(move |_| {
+            let filter = filter.clone();
+            save.edit(move |save| {
+                items.give_items(save, &filter);
+            })
+        });
+        dialog.dispatch(Some(
+            DialogLayout::YesNo {
+                title: None,
+                message: html! {
+                    <Text path="item_give_all_confirm" args={vec![
+                        ("added".into(), added.into()),
+                        ("refilled".into(), refilled.into()),
+                        ("no_space".into(), no_space.into()),
+                    ]} />
+                },
+                yes_callback,
+                no_callback: Callback::from(|_| ()),
+            }
+            .into(),
+        ))
+    });
+
+    html! {
+        <Button onclick={on_click}>
+            <Text path="item_give_all" />
+        </Button>
+    }
+}
+
+/// Removes the "new" marker from all items of the type.
+#[function_component]
+fn MarkAllSeen(props: &ItemTypeProps) -> Html {
+    let item_type = props.item_type;
+    let save = use_context::<SaveContext>().unwrap();
+
+    let on_click = Callback::from(move |_: MouseEvent| {
+        save.edit(move |save| save.inventory.set_all_new(item_type, false))
+    });
+
+    html! {
+        <Button onclick={on_click}>
+            <Text path="item_mark_all_seen" />
+        </Button>
+    }
+}
+
 fn index_of_item(
     inventory: &Inventory,
     item_type: ItemType,
@@ -4,6 +4,7 @@ use yew_router::Routable;
 mod about;
 mod character;
 mod chrono;
+mod collepedia;
 mod dlc;
 mod enemy;
 mod field;
@@ -14,6 +15,7 @@ mod item;
 mod meta;
 mod ouroboros;
 mod quest;
+mod system;
 
 #[derive(Routable, Clone, PartialEq, Copy)]
 pub enum Route {
@@ -35,6 +37,8 @@ pub enum Route {
     Quests,
     #[at("/uniques")]
     Uniques,
+    #[at("/collepedia")]
+    Collepedia,
     #[at("/formations")]
     Formations,
     #[at("/powaugment")]
@@ -51,6 +55,14 @@ pub enum Route {
     Flags,
     #[at("/chrono")]
     ChronoData,
+    #[at("/system/meta")]
+    SystemMeta,
+    #[at("/system/settings")]
+    SystemSettings,
+    #[at("/system/costumes")]
+    Costumes,
+    #[at("/system/theater")]
+    EventTheater,
 }
 
 pub fn render(route: Route) -> Html {
@@ -64,6 +76,7 @@ pub fn render(route: Route) -> Html {
         Route::Field => html!(<field::FieldPage />),
         Route::Quests => html!(<quest::Quests />),
         Route::Uniques => html!(<enemy::EnemyPage />),
+        Route::Collepedia => html!(<collepedia::CollepediaPage />),
         Route::Formations => html!(<formation::Formations />),
         Route::PowAugment => html!(<dlc::pow_augment::PowAugmentPage />),
         Route::ChallengeBattle => html!(<dlc::challenge::ChallengePage />),
@@ -72,5 +85,9 @@ pub fn render(route: Route) -> Html {
         Route::Dlc4Enemypedia => html!(),
         Route::Flags => html!(<flags::FlagList />),
         Route::ChronoData => html!(<chrono::ChronoPage />),
+        Route::SystemMeta => html!(<system::meta::SystemMeta />),
+        Route::SystemSettings => html!(<system::settings::SystemSettings />),
+        Route::Costumes => html!(<system::costume::CostumePage />),
+        Route::EventTheater => html!(<system::event_theater::EventTheaterPage />),
     }
 }
@@ -0,0 +1,107 @@
+use game_data::{
+    character::{Character, CostumeError},
+    lang::Nameable,
+};
+use ybc::{Button, Container, Control, Field, Table, Tile};
+use yew::prelude::*;
+
+use crate::{
+    components::{edit::CheckboxInput, select::Selector, system::CostumeEditor},
+    data::Data,
+    dialog::{DialogLayout, DialogQueue, Severity},
+    lang::Text,
+    save::SaveContext,
+};
+
+/// Only the main 6 characters can wear costumes.
+const COSTUME_CHARACTERS: usize = 6;
+
+#[function_component]
+pub fn CostumePage() -> Html {
+    let char_id = use_state(|| 1);
+    let data = use_context::<Data>().unwrap();
+    let save = use_context::<SaveContext>().unwrap();
+    let dialog_context = use_context::<DialogQueue>().unwrap();
+    let lang = data.to_lang();
+
+    let game = data.game();
+    let characters: &'static [Character] = &game.characters.characters()[..COSTUME_CHARACTERS];
+    let costumes = game
+        .characters
+        .costume_status(*char_id, save.get().get_system())
+        .map(|(costume, status)| (*costume, status.is_ok()))
+        .collect::<Vec<_>>();
+
+    let unlock_all = {
+        let char_id = *char_id;
+        Callback::from(move |_: MouseEvent| {
+            let dialog_context = dialog_context.clone();
+            save.edit_system(move |system| {
+                if let Err(CostumeError::OutOfBounds(id)) =
+                    game.characters.unlock_costumes(char_id, system)
+                {
+                    dialog_context.dispatch(Some(
+                        DialogLayout::Ok {
+                            title: None,
+                            message: html!(<Text path="system_costume_error" args={vec![("id".into(), id.into())]} />),
+                            severity: Severity::Warning,
+                        }
+                        .into(),
+                    ))
+                }
+            })
+        })
+    };
+
+    html! {
+        <Container>
+            <Tile classes={classes!("mb-2", "is-align-items-end")}>
+                <Tile>
+                    <Field>
+                        <label class="label"><Text path="character_character" /></label>
+                        <Control>
+                            <Selector<Character> state={char_id.clone()} values={characters} />
+                        </Control>
+                    </Field>
+                </Tile>
+                <Tile classes={classes!("is-justify-content-right")}>
+                    <Button onclick={unlock_all}>
+                        <Text path="system_costume_unlock_all" />
+                    </Button>
+                </Tile>
+            </Tile>
+
+            <Table classes={classes!("is-fullwidth")}>
+                <thead>
+                    <tr>
+                        <th><Text path="system_costume_id" /></th>
+                        <th><Text path="system_costume_name" /></th>
+                        <th><Text path="system_costume_unlocked" /></th>
+                    </tr>
+                </thead>
+
+                <tbody>
+                    {for costumes.into_iter().map(|(costume, valid)| html! {
+                        <tr>
+                            <th>{costume.id.to_string()}</th>
+                            <td>
+                                {costume.get_name_str(&lang).map(Html::from).unwrap_or_else(|| html! {
+                                    <Text path="unnamed" args={vec![("id".into(), costume.id.into())]} />
+                                })}
+                            </td>
+                            <td>
+                                if valid {
+                                    <CheckboxInput<CostumeEditor>
+                                        editor={CostumeEditor { costume, char_id: *char_id }}
+                                    />
+                                } else {
+                                    <Text path="system_costume_out_of_bounds" />
+                                }
+                            </td>
+                        </tr>
+                    })}
+                </tbody>
+            </Table>
+        </Container>
+    }
+}
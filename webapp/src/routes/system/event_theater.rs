@@ -0,0 +1,120 @@
+use game_data::{lang::Nameable, system::TheaterEvent};
+use ybc::{Button, Buttons, Container, Table, Tile};
+use yew::prelude::*;
+
+use crate::{
+    components::{
+        edit::CheckboxInput,
+        page::{PageControls, PageOrganizer},
+        system::EventEditor,
+    },
+    data::Data,
+    lang::Text,
+    save::SaveContext,
+};
+
+const PAGES_PER_VIEW: usize = 2;
+const ROWS_PER_PAGE: usize = 12;
+
+#[derive(Properties, PartialEq)]
+struct TableProps {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Properties, PartialEq)]
+struct EventProps {
+    pub event: TheaterEvent,
+}
+
+#[function_component]
+pub fn EventTheaterPage() -> Html {
+    let save = use_context::<SaveContext>().unwrap();
+    let data = use_context::<Data>().unwrap();
+
+    let events = data.game().system.events();
+
+    let page = use_state(|| 0);
+    let page_organizer = PageOrganizer::<PAGES_PER_VIEW>::new(ROWS_PER_PAGE, *page, events.len());
+
+    let unlock_all = {
+        let save = save.clone();
+        let ids = events.iter().map(|e| e.id).collect::<Vec<_>>();
+        Callback::from(move |_: MouseEvent| {
+            let ids = ids.clone();
+            save.edit_system(move |system| system.unlock_events(ids))
+        })
+    };
+    let lock_all =
+        Callback::from(move |_: MouseEvent| save.edit_system(|system| system.lock_all_events()));
+
+    html! {
+        <Container>
+            <Buttons classes={classes!("mb-2")}>
+                <Button onclick={unlock_all}>
+                    <Text path="event_theater_unlock_all" />
+                </Button>
+                <Button onclick={lock_all}>
+                    <Text path="event_theater_lock_all" />
+                </Button>
+            </Buttons>
+
+            <Tile classes="mb-2">
+                {for page_organizer.current_bounds.into_iter().map(|(s, e)| html! {
+                    <Tile>
+                        <TablePage start={s} end={e} />
+                    </Tile>
+                })}
+            </Tile>
+
+            <PageControls<PAGES_PER_VIEW> organizer={page_organizer} state={page} />
+        </Container>
+    }
+}
+
+#[function_component]
+fn TablePage(props: &TableProps) -> Html {
+    let data = use_context::<Data>().unwrap();
+    let events = &data.game().system.events()[props.start..=props.end];
+
+    html! {
+        <Table classes={classes!("is-fullwidth")}>
+            <thead>
+                <tr>
+                    <th><Text path="event_theater_id" /></th>
+                    <th><Text path="event_theater_chapter" /></th>
+                    <th><Text path="event_theater_name" /></th>
+                    <th><Text path="event_theater_unlocked" /></th>
+                </tr>
+            </thead>
+
+            <tbody>
+                {for events.iter().map(|event| {
+                    html!(<EventRow event={*event} />)
+                })}
+            </tbody>
+        </Table>
+    }
+}
+
+#[function_component]
+fn EventRow(props: &EventProps) -> Html {
+    let data = use_context::<Data>().unwrap();
+    let lang = data.to_lang();
+    let event = props.event;
+
+    html! {
+        <tr>
+            <th>{event.id.to_string()}</th>
+            <td>{event.chapter.to_string()}</td>
+            <td>
+                {event.get_name_str(&lang).map(Html::from).unwrap_or_else(|| html! {
+                    <Text path="unnamed" args={vec![("id".into(), event.id.into())]} />
+                })}
+            </td>
+            <td>
+                <CheckboxInput<EventEditor> editor={EventEditor(event.id)} />
+            </td>
+        </tr>
+    }
+}
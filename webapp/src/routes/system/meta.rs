@@ -0,0 +1,103 @@
+use recordkeeper::{SystemFlag, SAVE_SLOT_MAX};
+use strum::IntoEnumIterator;
+use ybc::{Container, Control, Field, Table, Tile, Title};
+use yew::prelude::*;
+
+use crate::{
+    components::{
+        edit::{CheckboxInput, NumberInput},
+        system::{CounterEditor, FlagEditor},
+    },
+    lang::Text,
+};
+
+#[function_component]
+pub fn SystemMeta() -> Html {
+    html! {
+        <Container>
+            <Tile>
+                <Tile classes={classes!("is-parent")}>
+                    <ClearFlags />
+                </Tile>
+                <Tile classes={classes!("is-parent")}>
+                    <Counters />
+                </Tile>
+            </Tile>
+        </Container>
+    }
+}
+
+#[function_component]
+fn ClearFlags() -> Html {
+    html! {
+        <Tile classes={classes!("is-child", "notification")}>
+            <Title><Text path="system_flags" /></Title>
+
+            {for SystemFlag::iter().map(|flag| html! {
+                <Field>
+                    <Control>
+                        <CheckboxInput<FlagEditor> editor={FlagEditor(flag)}>
+                            {" "}<Text path={format!("system_flag_{}", flag_lang(flag))} />
+                        </CheckboxInput<FlagEditor>>
+                    </Control>
+                </Field>
+            })}
+        </Tile>
+    }
+}
+
+#[function_component]
+fn Counters() -> Html {
+    html! {
+        <Tile classes={classes!("is-child", "notification")}>
+            <Title><Text path="system_counters" /></Title>
+
+            <Table classes={classes!("is-fullwidth")}>
+                <thead>
+                    <tr>
+                        <th><Text path="system_counter_slot" /></th>
+                        <th><Text path="system_counter_save" /></th>
+                        <th><Text path="system_counter_load" /></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for (0..SAVE_SLOT_MAX).map(|slot| html! {
+                        <tr>
+                            <th>{slot.to_string()}</th>
+                            <td><NumberInput<CounterEditor> editor={CounterEditor::Save(slot)} /></td>
+                            <td><NumberInput<CounterEditor> editor={CounterEditor::Load(slot)} /></td>
+                        </tr>
+                    })}
+                </tbody>
+            </Table>
+
+            <Field>
+                <label class="label"><Text path="system_counter_continue" /></label>
+                <Control>
+                    <NumberInput<CounterEditor> editor={CounterEditor::Continue} />
+                </Control>
+            </Field>
+
+            <Field>
+                <label class="label"><Text path="system_counter_settings" /></label>
+                <Control>
+                    <NumberInput<CounterEditor> editor={CounterEditor::SettingsSave} />
+                </Control>
+            </Field>
+        </Tile>
+    }
+}
+
+fn flag_lang(flag: SystemFlag) -> &'static str {
+    match flag {
+        SystemFlag::MainGameClear => "clear",
+        SystemFlag::MainGameClearNgp => "clear_ngp",
+        SystemFlag::EnableCostumes => "costumes",
+        SystemFlag::AmiiboRewards1 => "amiibo_1",
+        SystemFlag::AmiiboRewards2 => "amiibo_2",
+        SystemFlag::AmiiboRewards3 => "amiibo_3",
+        SystemFlag::Dlc4Clear => "dlc4_clear",
+        SystemFlag::Dlc4ClearNgp => "dlc4_clear_ngp",
+        SystemFlag::Amiibo4Support => "amiibo_4",
+    }
+}
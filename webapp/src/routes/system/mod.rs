@@ -0,0 +1,4 @@
+pub mod costume;
+pub mod event_theater;
+pub mod meta;
+pub mod settings;
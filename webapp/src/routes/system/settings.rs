@@ -0,0 +1,98 @@
+use game_data::{lang::Nameable, system::GameOption};
+use ybc::{Container, Table, Tile};
+use yew::prelude::*;
+
+use crate::{
+    components::{
+        edit::NumberInput,
+        page::{PageControls, PageOrganizer},
+        system::SettingEditor,
+    },
+    data::Data,
+    lang::Text,
+};
+
+const PAGES_PER_VIEW: usize = 2;
+const ROWS_PER_PAGE: usize = 12;
+
+#[derive(Properties, PartialEq)]
+struct TableProps {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Properties, PartialEq)]
+struct OptionProps {
+    pub option: GameOption,
+}
+
+#[function_component]
+pub fn SystemSettings() -> Html {
+    let data = use_context::<Data>().unwrap();
+    let options = data.game().system.options();
+
+    let page = use_state(|| 0);
+    let page_organizer = PageOrganizer::<PAGES_PER_VIEW>::new(ROWS_PER_PAGE, *page, options.len());
+
+    html! {
+        <Container>
+            <Tile classes="mb-2">
+                {for page_organizer.current_bounds.into_iter().map(|(s, e)| html! {
+                    <Tile>
+                        <TablePage start={s} end={e} />
+                    </Tile>
+                })}
+            </Tile>
+
+            <PageControls<PAGES_PER_VIEW> organizer={page_organizer} state={page} />
+        </Container>
+    }
+}
+
+#[function_component]
+fn TablePage(props: &TableProps) -> Html {
+    let data = use_context::<Data>().unwrap();
+    let options = &data.game().system.options()[props.start..=props.end];
+
+    html! {
+        <Table classes={classes!("is-fullwidth")}>
+            <thead>
+                <tr>
+                    <th><Text path="system_setting_id" /></th>
+                    <th><Text path="system_setting_name" /></th>
+                    <th><Text path="system_setting_value" /></th>
+                </tr>
+            </thead>
+
+            <tbody>
+                {for options.iter().map(|option| {
+                    html!(<OptionRow option={option.clone()} />)
+                })}
+            </tbody>
+        </Table>
+    }
+}
+
+#[function_component]
+fn OptionRow(props: &OptionProps) -> Html {
+    let data = use_context::<Data>().unwrap();
+    let lang = data.to_lang();
+    let option = &props.option;
+
+    html! {
+        <tr>
+            <th>{option.option_id.to_string()}</th>
+            <td>
+                {option.get_name_str(&lang).map(Html::from).unwrap_or_else(|| html! {
+                    <span>{&*option.table}{" #"}{option.row_id.to_string()}</span>
+                })}
+            </td>
+            <td>
+                {match SettingEditor::new(option.option_id) {
+                    Some(editor) => html!(<NumberInput<SettingEditor> editor={editor} />),
+                    None => html!(<Text path="system_setting_out_of_bounds" />),
+                }}
+            </td>
+        </tr>
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 use anyhow::{anyhow, Result};
 use gloo::file::{Blob, FileReadError, ObjectUrl};
 use log::info;
-use recordkeeper::{DataFile, SaveData, SaveFile, SaveResult};
+use recordkeeper::{DataFile, SaveData, SaveFile, SaveResult, SystemData};
 use wasm_bindgen::JsCast;
 use web_sys::HtmlAnchorElement;
 use yew::prelude::*;
@@ -31,11 +31,13 @@ pub enum EditAction {
     ClearError,
     Edit(EditFn),
     TryEdit(TryEditFn),
+    EditSystem(SystemEditFn),
     Download,
 }
 
 pub type EditFn = Box<dyn FnOnce(&mut SaveData)>;
 pub type TryEditFn = Box<dyn FnOnce(&mut SaveData) -> Result<()>>;
+pub type SystemEditFn = Box<dyn FnOnce(&mut SystemData)>;
 
 #[derive(Properties, PartialEq)]
 pub struct SaveProviderProps {
@@ -93,6 +95,26 @@ impl SaveManager {
             .unwrap()
     }
 
+    pub fn get_system(&self) -> &SystemData {
+        self.save_buffers[0]
+            .as_ref()
+            .map(|s| match &s.file {
+                DataFile::System(system) => system.save(),
+                _ => panic!("current file is not a system file"),
+            })
+            .unwrap()
+    }
+
+    pub fn get_system_mut(&mut self) -> &mut SystemData {
+        self.save_buffers[0]
+            .as_mut()
+            .map(|s| match &mut s.file {
+                DataFile::System(system) => system.save_mut(),
+                _ => panic!("current file is not a system file"),
+            })
+            .unwrap()
+    }
+
     pub fn is_system(&self) -> bool {
         self.save_buffers[0]
             .as_ref()
@@ -185,6 +207,10 @@ impl SaveContext {
         self.submit_action(EditAction::TryEdit(Box::new(editor)))
     }
 
+    pub fn edit_system(&self, editor: impl FnOnce(&mut SystemData) + 'static) {
+        self.submit_action(EditAction::EditSystem(Box::new(editor)))
+    }
+
     pub fn get(&self) -> Ref<'_, SaveManager> {
         self.handle.manager.borrow()
     }
@@ -210,6 +236,11 @@ impl Reducible for SaveReducer {
             EditAction::TryEdit(callback) => {
                 callback(handle.get_save_mut()).map(|_| handle.mark_change())
             }
+            EditAction::EditSystem(callback) => {
+                callback(handle.get_system_mut());
+                handle.mark_change();
+                Ok(())
+            }
             EditAction::ClearError => Ok(()),
             EditAction::Download => handle.download(),
         };